@@ -1,8 +1,29 @@
 fn main() {
     println!("cargo:rerun-if-changed=build.rs");
+    // set if we use the glfw 3.4 bindings. Not set in glfw-3-3 mode, when pkg-config finds glfw 3.3.
+    println!("cargo:rustc-check-cfg=cfg(glfw_3_4)");
+    // set if bindgen also generated bindings for a glfw 3.3 header (see `generate_bindings`).
+    println!("cargo:rustc-check-cfg=cfg(glfw_bindgen_3_3)");
 
     // get enabled features
-    let features = Features::default();
+    let mut features = Features::default();
+    // env vars take precedence over cargo features
+    let env_overrides = EnvOverrides::default();
+    // None, unless the target is not the host.
+    let cross = CrossCompile::detect();
+    if let Some(cross) = &cross {
+        println!("cross compiling: {cross:#?}");
+    }
+    if let Some(static_link) = env_overrides.static_link {
+        features.static_link = static_link;
+    }
+    // the cc build only produces a static archive.
+    if features.cc_build {
+        if env_overrides.static_link == Some(false) {
+            println!("cargo:warning=ignoring GLFW_STATIC=0, as src-build-cc always links glfw statically");
+        }
+        features.static_link = true;
+    }
 
     #[allow(
         unused,
@@ -11,7 +32,9 @@ fn main() {
     let out_dir = std::env::var("OUT_DIR").expect("failed to get out dir");
 
     // just print a warning to tell them to enable x11/wayland for non-mainstream platforms like freebsd etc.
-    if TargetOs::Others == features.os && !(features.x11 || features.wayland || features.osmesa) {
+    if TargetOs::Others == features.os
+        && !(features.x11 || features.wayland || features.osmesa || features.null_platform)
+    {
         println!(
         "cargo:warning=unsupported os/platform. you may want to choose x11 or wayland features for linux-like targets"
         );
@@ -23,6 +46,7 @@ fn main() {
 
     // Lets skip everything else on docs.rs builds
     if features.docs_rs {
+        println!("cargo:rustc-cfg=glfw_3_4");
         return;
     }
     // lets special case emscripten and early return.
@@ -32,29 +56,78 @@ fn main() {
         // Without this, we get errors like
         // = note: wasm-ld: error: .../basic.diqs9uv01tyf3yxt0iu6v8zc8.rcgu.o: undefined symbol: glfwGetError
         println!("cargo:rustc-link-arg=-sERROR_ON_UNDEFINED_SYMBOLS=0");
+        println!("cargo:rustc-cfg=glfw_3_4");
+        return;
+    }
+    if features.null_platform && !features.src_build {
+        println!("cargo:warning=null-platform only affects src-build. The linked glfw may still contain other platforms");
+    }
+    // with dlopen, glfw is loaded at runtime. So, we must not link it (or any of its dependencies).
+    if features.dlopen {
+        println!("cargo:rustc-cfg=glfw_3_4");
         return;
     }
 
+    // user provided their own glfw via GLFW_LIB_DIR => skip pkg-config, cmake and download.
+    // With GLFW_NO_PKG_CONFIG, we link the system glfw by name instead of asking pkg-config.
+    let env_build = env_overrides.lib_dir.is_some()
+        || (env_overrides.no_pkg_config && !features.src_build && !features.prebuilt_libs);
     // not src build and not prebuilt-libs => use pkg-config
-    let pkgconfig_build = !features.src_build && !features.prebuilt_libs;
-    if features.src_build {
+    let pkgconfig_build = !env_build && !features.src_build && !features.prebuilt_libs;
+    let mut lib_info = if env_build {
+        env_overrides.link()
+    } else if features.cc_build {
+        // build from src with cc, for sandboxes without cmake.
+        #[cfg(feature = "src-build-cc")]
+        {
+            build_with_cc(features, &out_dir, &env_overrides, cross.as_ref())
+        }
+        #[cfg(not(feature = "src-build-cc"))]
+        unreachable!("cc_build is only enabled with src-build-cc feature")
+    } else if features.src_build {
         // build from src, instead of using prebuilt-libraries.
         #[cfg(feature = "src-build")]
-        build_from_src(features, &out_dir);
+        {
+            build_from_src(features, &out_dir, &env_overrides, cross.as_ref())
+        }
+        #[cfg(not(feature = "src-build"))]
+        unreachable!("src_build is only enabled with src-build feature")
     } else if features.prebuilt_libs {
-        download_libs(features, &out_dir);
+        #[cfg(feature = "prebuilt-libs")]
+        {
+            download_libs(features, &out_dir)
+        }
+        #[cfg(not(feature = "prebuilt-libs"))]
+        unreachable!("prebuilt_libs is only enabled with prebuilt-libs feature")
     } else {
         assert!(pkgconfig_build);
         // emits linker flags by default.
-        match pkg_config::Config::new()
-            .statik(features.static_link)
-            .atleast_version("3.4.0")
-            .probe("glfw3")
-        {
-            Ok(lib) => println!("pkg-config found glfw library {lib:#?}"),
-            Err(e) => panic!("pkg-config failed to find glfw library: {e}"),
-        }
+        let lib = probe_system_lib(
+            pkg_config::Config::new()
+                .statik(features.static_link)
+                .atleast_version(if features.glfw_3_3 { "3.3.0" } else { "3.4.0" }),
+            "glfw3",
+            cross.as_ref(),
+        );
+        println!("pkg-config found glfw library {lib:#?}");
+        LibInfo::from_pkg_config(&lib)
+    };
+    if let Some(include_dir) = &env_overrides.include_dir {
+        lib_info.version = header_version(&include_dir.join("GLFW/glfw3.h")).or(lib_info.version);
+        lib_info.include_dir = Some(include_dir.clone());
+    }
+    // src-build and prebuilt-libs always use glfw 3.4, but pkg-config or GLFW_INCLUDE_DIR may find 3.3.
+    let glfw_3_4 = !(features.glfw_3_3
+        && lib_info
+            .version
+            .as_deref()
+            .is_some_and(|version| version.starts_with("3.3.")));
+    if glfw_3_4 {
+        println!("cargo:rustc-cfg=glfw_3_4");
+    } else if features.bindgen {
+        panic!("bindgen generates bindings from the bundled glfw 3.4 headers, so it can't be used with glfw 3.3 (glfw-3-3 feature)");
     }
+    lib_info.emit_metadata(features, glfw_3_4);
 
     // pkg-config takes care of emitting linker flags, so we only explicitly
     // need to emit them if we aren't using pkg-config.
@@ -100,18 +173,38 @@ fn main() {
             println!("cargo:rustc-link-lib=framework=OpenGL");
             println!("cargo:rustc-link-lib=framework=QuartzCore");
         }
+        // without pkg-config, we just hope that the linker finds these libs in its default search paths.
+        TargetOs::Linux | TargetOs::Others if env_overrides.skip_dependency_probes() => {
+            if features.x11 {
+                println!("cargo:rustc-link-lib=X11");
+            }
+            if features.wayland {
+                println!("cargo:rustc-link-lib=wayland-client");
+            }
+        }
+        // a self-contained binary, so every dependency (and their dependencies) is linked statically.
+        TargetOs::Linux | TargetOs::Others if features.musl && features.static_link => {
+            if features.crt_static && (features.x11 || features.wayland) {
+                println!("cargo:warning=glfw loads the x11/wayland libraries at runtime with dlopen, which is not supported in static (crt-static) musl binaries. So, glfwInit will fail for x11/wayland (only the null platform works), even though the libraries are linked statically");
+            }
+            for lib in features.system_libs() {
+                println!(
+                    "pkg-config static {lib} lib: {:#?}",
+                    probe_system_lib(pkg_config::Config::new().statik(true), lib, cross.as_ref())
+                );
+            }
+        }
         TargetOs::Linux | TargetOs::Others => {
             if features.x11 {
                 println!(
                     "pkg-config x11 lib: {:#?}",
-                    pkg_config::probe_library("x11").expect("pkg-config failed to find x11")
+                    probe_system_lib(&pkg_config::Config::new(), "x11", cross.as_ref())
                 );
             }
             if features.wayland {
                 println!(
                     "pkg-config wayland-client lib: {:#?}",
-                    pkg_config::probe_library("wayland-client")
-                        .expect("pkg-config failed to find wayland-client")
+                    probe_system_lib(&pkg_config::Config::new(), "wayland-client", cross.as_ref())
                 );
             }
         }
@@ -158,14 +251,28 @@ struct Features {
     /// generate bindings for native gl bindings like wgl, glx, nsgl, egl etc..
     /// For X11, you can explicitly enable egl-related functionality using `egl` feature.
     gl: bool,
-    /// whether we are doing a src build
+    /// whether we are doing a src build (with cmake or cc)
     src_build: bool,
+    /// whether the src build compiles the sources with cc, instead of cmake.
+    cc_build: bool,
     /// whether we are using prebuilt libs
     /// This is only true if feature is enabled AND target is win/mac
     prebuilt_libs: bool,
     /// whether we are running on docs.rs
     /// We want to skip building/linking etc.. to avoid failing on docs.rs builds.
     docs_rs: bool,
+    /// whether glfw will be loaded at runtime. We skip building/linking entirely.
+    dlopen: bool,
+    /// build glfw with only the null platform (for headless CI).
+    /// This disables x11/wayland (even if their features are enabled by some other crate).
+    null_platform: bool,
+    /// accept glfw 3.3 from pkg-config, and use the 3.3 bindings if we find it.
+    glfw_3_3: bool,
+    /// whether the target uses musl libc (eg: `x86_64-unknown-linux-musl`).
+    /// With `static_link`, the x11/wayland dependencies are linked statically too.
+    musl: bool,
+    /// whether the C runtime is linked statically (default for musl targets).
+    crt_static: bool,
 }
 /// Use `cfg` macro to get the selected features.
 impl Default for Features {
@@ -196,12 +303,29 @@ impl Default for Features {
                 bindgen,
                 gl: false,
                 src_build: false,
+                cc_build: false,
                 docs_rs,
                 prebuilt_libs: false,
+                dlopen: false,
+                null_platform: false,
+                glfw_3_3: false,
+                musl: false,
+                crt_static: false,
             };
         }
         // on docs-rs builds, skip vulkan on non-linux platforms, as they lack VULKAN_SDK headers
         let skip_vulkan = docs_rs && os != TargetOs::Linux;
+        let null_platform = cfg!(feature = "null-platform");
+        let musl = std::env::var("CARGO_CFG_TARGET_ENV").is_ok_and(|env| env == "musl");
+        // cargo queries the cfg with dylib crate types, which turns off crt-static on musl.
+        // So, for musl we check if the user turned it off instead (it is the default).
+        let crt_static = if musl {
+            !std::env::var("CARGO_ENCODED_RUSTFLAGS")
+                .is_ok_and(|flags| flags.contains("-crt-static"))
+        } else {
+            std::env::var("CARGO_CFG_TARGET_FEATURE")
+                .is_ok_and(|features| features.split(',').any(|feature| feature == "crt-static"))
+        };
         Self {
             static_link: cfg!(feature = "static-link"),
 
@@ -211,23 +335,391 @@ impl Default for Features {
             os,
             bindgen,
             docs_rs,
-            wayland: cfg!(feature = "wayland"),
-            x11: cfg!(feature = "x11"),
+            wayland: cfg!(feature = "wayland") && !null_platform,
+            x11: cfg!(feature = "x11") && !null_platform,
             egl: cfg!(feature = "native-egl"),
             osmesa: cfg!(feature = "osmesa"),
             gl: cfg!(feature = "native-gl"),
-            src_build: cfg!(feature = "src-build"),
+            src_build: cfg!(feature = "src-build") || cfg!(feature = "src-build-cc"),
+            cc_build: cfg!(feature = "src-build-cc"),
             // this feature only works on windows and mac
             prebuilt_libs: cfg!(feature = "prebuilt-libs")
                 && (os == TargetOs::Win || os == TargetOs::Mac),
+            dlopen: cfg!(feature = "dlopen"),
+            null_platform,
+            glfw_3_3: cfg!(feature = "glfw-3-3"),
+            musl,
+            crt_static,
+        }
+    }
+}
+impl Features {
+    /// The glfw platform backends enabled for this build, named like glfw's `GLFW_BUILD_*` options.
+    /// The null platform is always built by glfw.
+    fn platforms(&self) -> Vec<&'static str> {
+        let mut platforms = vec![];
+        match self.os {
+            _ if self.null_platform => {}
+            TargetOs::Win => platforms.push("win32"),
+            TargetOs::Mac => platforms.push("cocoa"),
+            TargetOs::Linux | TargetOs::Others => {
+                if self.x11 {
+                    platforms.push("x11");
+                }
+                if self.wayland {
+                    platforms.push("wayland");
+                }
+            }
+            TargetOs::Emscripten => {}
+        }
+        platforms.push("null");
+        platforms
+    }
+    /// The pkg-config names of the libraries that glfw's CMakeLists.txt requires for the
+    /// enabled x11/wayland backends (glfw needs their headers, and loads them at runtime).
+    fn system_libs(&self) -> Vec<&'static str> {
+        let mut libs = vec![];
+        if self.x11 {
+            // Xkb is part of x11, and Xshape is part of xext.
+            libs.extend(["x11", "xrandr", "xinerama", "xcursor", "xi", "xext"]);
+        }
+        if self.wayland {
+            libs.extend([
+                "wayland-client",
+                "wayland-cursor",
+                "wayland-egl",
+                "xkbcommon",
+            ]);
+        }
+        libs
+    }
+}
+/// Overrides for finding/linking glfw, set by env vars.
+///
+/// These take precedence over [`Features`], and are meant for people who bring their own glfw
+/// (eg: installed in a non-standard prefix).
+#[derive(Debug)]
+struct EnvOverrides {
+    /// `GLFW_LIB_DIR` - directory containing the glfw library to link.
+    /// If set, we skip pkg-config (also for x11/wayland), source builds and downloads.
+    lib_dir: Option<std::path::PathBuf>,
+    /// `GLFW_INCLUDE_DIR` - directory containing `GLFW/glfw3.h`.
+    /// Only used for the `DEP_GLFW_INCLUDE` build metadata (and the glfw version).
+    include_dir: Option<std::path::PathBuf>,
+    /// `GLFW_STATIC` - `1` to link statically, `0` to link dynamically.
+    /// overrides `static-link` feature.
+    static_link: Option<bool>,
+    /// `GLFW_NO_PKG_CONFIG` - never use pkg-config (for glfw or its dependencies like x11).
+    /// The libs are linked by name, so the linker finds them in its default search paths.
+    /// `src-build` and `prebuilt-libs` still build/download glfw.
+    no_pkg_config: bool,
+    /// `GLFW_CMAKE_ARGS` - extra whitespace separated cmake args for `src-build`
+    /// (eg: `-DGLFW_USE_HYBRID_HPG=ON -DCMAKE_POSITION_INDEPENDENT_CODE=ON`).
+    /// `-D` defines are applied after ours, so they can override them.
+    /// Args are split on whitespace without any quoting, so values can't contain spaces.
+    /// Use a toolchain file (`GLFW_CMAKE_TOOLCHAIN_FILE`) for those.
+    #[allow(unused, reason = "only used by src-build")]
+    cmake_args: Vec<String>,
+    /// `GLFW_CMAKE_TOOLCHAIN_FILE` - `CMAKE_TOOLCHAIN_FILE` for `src-build`.
+    #[allow(unused, reason = "only used by src-build")]
+    cmake_toolchain_file: Option<std::path::PathBuf>,
+}
+impl EnvOverrides {
+    /// Whether to link x11/wayland by name, instead of probing them with pkg-config.
+    ///
+    /// A user provided glfw (`GLFW_LIB_DIR`) is often used because pkg-config is not
+    /// available, so we don't probe its dependencies either.
+    fn skip_dependency_probes(&self) -> bool {
+        self.no_pkg_config || self.lib_dir.is_some()
+    }
+}
+impl Default for EnvOverrides {
+    fn default() -> Self {
+        let path = |name: &str| {
+            println!("cargo:rerun-if-env-changed={name}");
+            std::env::var_os(name)
+                .filter(|value| !value.is_empty())
+                .map(std::path::PathBuf::from)
+        };
+        Self {
+            lib_dir: path("GLFW_LIB_DIR"),
+            include_dir: path("GLFW_INCLUDE_DIR"),
+            static_link: env_flag("GLFW_STATIC"),
+            no_pkg_config: env_flag("GLFW_NO_PKG_CONFIG").unwrap_or_default(),
+            cmake_args: {
+                println!("cargo:rerun-if-env-changed=GLFW_CMAKE_ARGS");
+                std::env::var("GLFW_CMAKE_ARGS")
+                    .unwrap_or_default()
+                    .split_whitespace()
+                    .map(str::to_string)
+                    .collect()
+            },
+            cmake_toolchain_file: path("GLFW_CMAKE_TOOLCHAIN_FILE"),
+        }
+    }
+}
+impl EnvOverrides {
+    /// Emits the link search path for `GLFW_LIB_DIR`.
+    /// The actual library is linked in main, just like the src/prebuilt builds.
+    fn link(&self) -> LibInfo {
+        if let Some(lib_dir) = &self.lib_dir {
+            if !lib_dir.exists() {
+                println!(
+                    "cargo:warning=GLFW_LIB_DIR {} doesn't exist",
+                    lib_dir.display()
+                );
+            }
+            println!("cargo:rustc-link-search=native={}", lib_dir.display());
+        }
+        LibInfo {
+            include_dir: None,
+            lib_dir: self.lib_dir.clone(),
+            version: None,
+        }
+    }
+}
+/// Reads a boolean env var like `GLFW_STATIC=1`. Returns `None` if it is not set.
+fn env_flag(name: &str) -> Option<bool> {
+    println!("cargo:rerun-if-env-changed={name}");
+    let value = std::env::var(name).ok()?;
+    match value.to_lowercase().as_str() {
+        "" => None,
+        "1" | "true" | "on" | "yes" => Some(true),
+        "0" | "false" | "off" | "no" => Some(false),
+        _ => panic!("invalid value {value:?} for {name}. expected 1 or 0"),
+    }
+}
+/// Details about cross compilation (when the target is not the host).
+///
+/// cmake-rs already picks the target compiler (like cc, eg: `CC_aarch64_unknown_linux_gnu`)
+/// and sets `CMAKE_SYSTEM_NAME`/`CMAKE_SYSTEM_PROCESSOR`. But neither cmake nor pkg-config
+/// know where the headers and libs of the target (x11, wayland etc..) are.
+#[derive(Debug)]
+struct CrossCompile {
+    /// `TARGET`, eg: `aarch64-unknown-linux-gnu`.
+    target: String,
+    /// `HOST`, eg: `x86_64-unknown-linux-gnu`.
+    host: String,
+    /// root filesystem of the target (eg: a mounted raspberry pi image).
+    /// `GLFW_SYSROOT`, or `PKG_CONFIG_SYSROOT_DIR` for the target.
+    sysroot: Option<std::path::PathBuf>,
+}
+impl CrossCompile {
+    fn detect() -> Option<Self> {
+        let target = std::env::var("TARGET").expect("failed to get target");
+        let host = std::env::var("HOST").expect("failed to get host");
+        if target == host {
+            return None;
+        }
+        let mut cross = Self {
+            target,
+            host,
+            sysroot: None,
+        };
+        println!("cargo:rerun-if-env-changed=GLFW_SYSROOT");
+        cross.sysroot = std::env::var("GLFW_SYSROOT")
+            .ok()
+            .filter(|sysroot| !sysroot.is_empty())
+            .or_else(|| cross.target_env("PKG_CONFIG_SYSROOT_DIR"))
+            .map(std::path::PathBuf::from);
+        if let Some(sysroot) = &cross.sysroot {
+            assert!(
+                sysroot.is_dir(),
+                "sysroot {} (from GLFW_SYSROOT or PKG_CONFIG_SYSROOT_DIR) is not a directory",
+                sysroot.display()
+            );
+        }
+        Some(cross)
+    }
+    /// Reads the env var `name` for the target, in the same order as the pkg-config and cc crates:
+    /// `<name>_<target>`, `<name>_<target_with_underscores>`, `TARGET_<name>` and `<name>`.
+    fn target_env(&self, name: &str) -> Option<String> {
+        [
+            format!("{name}_{}", self.target),
+            format!("{name}_{}", self.target.replace('-', "_")),
+            format!("TARGET_{name}"),
+            name.to_string(),
+        ]
+        .into_iter()
+        .find_map(|var| {
+            println!("cargo:rerun-if-env-changed={var}");
+            std::env::var(&var).ok().filter(|value| !value.is_empty())
+        })
+    }
+    /// Makes cmake search the sysroot for the target's headers/libs (but not for programs like
+    /// `wayland-scanner`, which must run on the host), unless a toolchain file takes care of it.
+    #[cfg(feature = "src-build")]
+    fn configure_cmake(&self, config: &mut cmake::Config, toolchain_file: bool) {
+        if let (Some(sysroot), false) = (&self.sysroot, toolchain_file) {
+            config
+                .define("CMAKE_SYSROOT", sysroot)
+                .define("CMAKE_FIND_ROOT_PATH", sysroot)
+                .define("CMAKE_FIND_ROOT_PATH_MODE_PROGRAM", "NEVER")
+                .define("CMAKE_FIND_ROOT_PATH_MODE_LIBRARY", "ONLY")
+                .define("CMAKE_FIND_ROOT_PATH_MODE_INCLUDE", "ONLY")
+                .define("CMAKE_FIND_ROOT_PATH_MODE_PACKAGE", "ONLY");
+        }
+        // glfw's cmake finds wayland/xkbcommon with pkg-config, which only reads the
+        // plain env vars. So, we resolve the target specific ones for it.
+        for name in [
+            "PKG_CONFIG_SYSROOT_DIR",
+            "PKG_CONFIG_PATH",
+            "PKG_CONFIG_LIBDIR",
+        ] {
+            if let Some(value) = self.target_env(name) {
+                config.env(name, value);
+            }
+        }
+    }
+    /// How to make pkg-config find `lib` for the target, for error messages.
+    fn pkg_config_hint(&self, lib: &str) -> String {
+        let target_ = self.target.replace('-', "_");
+        let arch = std::env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+        // eg: aarch64-linux-gnu or arm-linux-gnueabihf
+        let multiarch = format!(
+            "{arch}-linux-{}",
+            self.target.rsplit('-').next().unwrap_or_default()
+        );
+        let debian_arch = match arch.as_str() {
+            "aarch64" => "arm64",
+            "arm" => "armhf",
+            "x86" => "i386",
+            "x86_64" => "amd64",
+            arch => arch,
+        };
+        let package = match lib {
+            "glfw3" => "libglfw3-dev",
+            "x11" => "libx11-dev",
+            "wayland-client" => "libwayland-dev",
+            "xkbcommon" => "libxkbcommon-dev",
+            _ => "<package>-dev",
+        };
+        format!(
+            "cross compiling for {target} (from {host}), so pkg-config must find {lib}.pc of the target, not the host. Either:
+- debian/ubuntu multiarch: install {package}:{debian_arch} and set PKG_CONFIG_ALLOW_CROSS=1 PKG_CONFIG_PATH_{target_}=/usr/lib/{multiarch}/pkgconfig
+- sysroot (eg: a raspberry pi image): set PKG_CONFIG_SYSROOT_DIR_{target_}=/path/to/sysroot PKG_CONFIG_LIBDIR_{target_}=/path/to/sysroot/usr/lib/{multiarch}/pkgconfig:/path/to/sysroot/usr/share/pkgconfig
+- set GLFW_NO_PKG_CONFIG=1 to skip pkg-config. The libs are then linked by name, so add the target's lib directory to the linker search path (eg: with `-L` in RUSTFLAGS)",
+            target = self.target,
+            host = self.host,
+        )
+    }
+}
+/// Probes `lib` with pkg-config, and panics with hints if it isn't found.
+fn probe_system_lib(
+    config: &pkg_config::Config,
+    lib: &str,
+    cross: Option<&CrossCompile>,
+) -> pkg_config::Library {
+    config.probe(lib).unwrap_or_else(|e| {
+        let hint = cross.map(|cross| cross.pkg_config_hint(lib));
+        panic!(
+            "pkg-config failed to find {lib}: {e}\n{}",
+            hint.unwrap_or_default()
+        )
+    })
+}
+/// Details about the glfw library we ended up linking.
+///
+/// These are exported to dependent crates' build scripts as `DEP_GLFW_*` env vars
+/// (because of `links = "glfw"` in Cargo.toml), so that they can compile C code against glfw.
+#[derive(Debug)]
+struct LibInfo {
+    /// directory containing `GLFW/glfw3.h`
+    include_dir: Option<std::path::PathBuf>,
+    /// directory containing the glfw library we link
+    lib_dir: Option<std::path::PathBuf>,
+    /// version of glfw, in `major.minor.revision` format.
+    version: Option<String>,
+}
+impl LibInfo {
+    /// Collects the details from a library found by pkg-config.
+    fn from_pkg_config(lib: &pkg_config::Library) -> Self {
+        // pkg-config skips system dirs like /usr/include in cflags/libs,
+        // so we fall back to the variables in glfw3.pc
+        let variable = |name: &str| {
+            pkg_config::get_variable("glfw3", name)
+                .ok()
+                .filter(|dir| !dir.is_empty())
+                .map(std::path::PathBuf::from)
+        };
+        let include_dir = lib
+            .include_paths
+            .iter()
+            .find(|dir| dir.join("GLFW/glfw3.h").exists())
+            .cloned()
+            .or_else(|| variable("includedir"));
+        let lib_dir = lib
+            .link_paths
+            .first()
+            .cloned()
+            .or_else(|| variable("libdir"));
+        Self {
+            include_dir,
+            lib_dir,
+            version: Some(lib.version.clone()),
         }
     }
+    /// Collects the details from an include dir and lib dir that we built/downloaded.
+    /// The version is read from the glfw header.
+    #[allow(
+        unused,
+        reason = "only used by src-build, src-build-cc or prebuilt-libs"
+    )]
+    fn from_dirs(include_dir: std::path::PathBuf, lib_dir: std::path::PathBuf) -> Self {
+        let version = header_version(&include_dir.join("GLFW/glfw3.h"));
+        Self {
+            include_dir: Some(include_dir),
+            lib_dir: Some(lib_dir),
+            version,
+        }
+    }
+    /// Prints `cargo:KEY=VALUE` lines, which become `DEP_GLFW_KEY` for dependents.
+    fn emit_metadata(&self, features: Features, glfw_3_4: bool) {
+        if let Some(include_dir) = &self.include_dir {
+            println!("cargo:INCLUDE={}", include_dir.display());
+        }
+        if let Some(lib_dir) = &self.lib_dir {
+            println!("cargo:LIB_DIR={}", lib_dir.display());
+        }
+        if let Some(version) = &self.version {
+            println!("cargo:VERSION={version}");
+        }
+        println!("cargo:STATIC={}", if features.static_link { 1 } else { 0 });
+        println!("cargo:PLATFORMS={}", features.platforms().join(","));
+        println!("cargo:GLFW_3_4={}", if glfw_3_4 { 1 } else { 0 });
+    }
+}
+/// Reads `GLFW_VERSION_MAJOR/MINOR/REVISION` defines from glfw3.h
+fn header_version(header: &std::path::Path) -> Option<String> {
+    let header = std::fs::read_to_string(header).ok()?;
+    let define = |name: &str| {
+        header.lines().find_map(|line| {
+            line.trim()
+                .strip_prefix("#define")?
+                .trim()
+                .strip_prefix(name)?
+                .trim()
+                .parse::<u32>()
+                .ok()
+        })
+    };
+    Some(format!(
+        "{}.{}.{}",
+        define("GLFW_VERSION_MAJOR")?,
+        define("GLFW_VERSION_MINOR")?,
+        define("GLFW_VERSION_REVISION")?
+    ))
 }
 /// builds from source using cmake.
 /// The sources are included with this crate.
 /// feature-gated to make cmake crate optional.
 #[cfg(feature = "src-build")]
-fn build_from_src(features: Features, _out_dir: &str) {
+fn build_from_src(
+    features: Features,
+    _out_dir: &str,
+    env_overrides: &EnvOverrides,
+    cross: Option<&CrossCompile>,
+) -> LibInfo {
     let mut config = cmake::Config::new("./glfw");
     let lib_dir = std::env::current_dir().unwrap().join("glfw");
     println!("cargo:THIRD_PARTY={}", lib_dir.display());
@@ -248,11 +740,47 @@ fn build_from_src(features: Features, _out_dir: &str) {
             config.define("GLFW_BUILD_X11", "OFF");
         }
     }
+    // x11/wayland are already disabled in features. So, we only need to disable native platforms.
+    if features.null_platform {
+        config
+            .define("GLFW_BUILD_WIN32", "OFF")
+            .define("GLFW_BUILD_COCOA", "OFF");
+    }
     if features.static_link {
         config.define("GLFW_LIBRARY_TYPE", "STATIC");
     } else {
         config.define("GLFW_LIBRARY_TYPE", "SHARED");
     }
+    if let Some(toolchain_file) = &env_overrides.cmake_toolchain_file {
+        config.define("CMAKE_TOOLCHAIN_FILE", toolchain_file);
+    }
+    if let Some(cross) = cross {
+        // cmake-rs also reads `CMAKE_TOOLCHAIN_FILE` for the target.
+        let toolchain_file = env_overrides.cmake_toolchain_file.is_some()
+            || cross.target_env("CMAKE_TOOLCHAIN_FILE").is_some();
+        cross.configure_cmake(&mut config, toolchain_file);
+    }
+    for arg in &env_overrides.cmake_args {
+        match arg
+            .strip_prefix("-D")
+            .and_then(|define| define.split_once('='))
+        {
+            // the key may have a type, like `-DCMAKE_BUILD_TYPE:STRING=Debug`.
+            // multi-config generators (eg: visual studio) use the profile for `--config` too,
+            // so we set the profile instead of defining `CMAKE_BUILD_TYPE`.
+            Some((key, value)) if key.split(':').next() == Some("CMAKE_BUILD_TYPE") => {
+                config.profile(value);
+            }
+            Some((key, value)) => {
+                config.define(key, value);
+            }
+            None => {
+                config.configure_arg(arg);
+            }
+        }
+    }
+    // Unless overridden above, cmake-rs picks the build type from cargo's profile.
+    println!("glfw cmake build type: {}", config.get_profile());
     let dst_dir = config.build();
     println!(
         "cargo:rustc-link-search=native={}",
@@ -264,14 +792,218 @@ fn build_from_src(features: Features, _out_dir: &str) {
             dst_dir.join("bin").display()
         );
     }
+    LibInfo::from_dirs(dst_dir.join("include"), dst_dir.join("lib"))
+}
+
+/// builds a static glfw from source with cc, mirroring the source lists of `glfw/src/CMakeLists.txt`.
+/// For build environments without cmake. Wayland protocol headers are generated with `wayland-scanner`.
+/// feature-gated to make cc crate optional.
+#[cfg(feature = "src-build-cc")]
+fn build_with_cc(
+    features: Features,
+    out_dir: &str,
+    env_overrides: &EnvOverrides,
+    cross: Option<&CrossCompile>,
+) -> LibInfo {
+    let src_dir = std::path::Path::new("./glfw/src");
+    let glfw_dir = std::env::current_dir().unwrap().join("glfw");
+    println!("cargo:THIRD_PARTY={}", glfw_dir.display());
+    println!("cargo:rerun-if-changed={}", src_dir.display());
+
+    let mut build = cc::Build::new();
+    // main links glfw3, just like the other builds.
+    build.cargo_metadata(false).warnings(false);
+    build.include("./glfw/include").include(src_dir);
+    // cc already picks the compiler of the target, but not the sysroot.
+    if let Some(sysroot) = cross.and_then(|cross| cross.sysroot.as_ref()) {
+        if !build.get_compiler().is_like_msvc() {
+            build.flag(format!("--sysroot={}", sysroot.display()));
+        }
+    }
+
+    let platforms = features.platforms();
+    let mut sources = vec![
+        "context.c",
+        "init.c",
+        "input.c",
+        "monitor.c",
+        "platform.c",
+        "vulkan.c",
+        "window.c",
+        "egl_context.c",
+        "osmesa_context.c",
+        "null_init.c",
+        "null_monitor.c",
+        "null_window.c",
+        "null_joystick.c",
+    ];
+    match features.os {
+        TargetOs::Win => {
+            sources.extend(["win32_module.c", "win32_time.c", "win32_thread.c"]);
+            build.define("UNICODE", None).define("_UNICODE", None);
+            if build.get_compiler().is_like_msvc() {
+                build.define("_CRT_SECURE_NO_WARNINGS", None);
+            }
+        }
+        TargetOs::Mac => sources.extend(["cocoa_time.c", "posix_module.c", "posix_thread.c"]),
+        _ => {
+            sources.extend(["posix_module.c", "posix_time.c", "posix_thread.c"]);
+            build.define("_DEFAULT_SOURCE", None);
+        }
+    }
+    for platform in &platforms {
+        match *platform {
+            "win32" => {
+                build.define("_GLFW_WIN32", None);
+                sources.extend([
+                    "win32_init.c",
+                    "win32_joystick.c",
+                    "win32_monitor.c",
+                    "win32_window.c",
+                    "wgl_context.c",
+                ]);
+            }
+            "cocoa" => {
+                build.define("_GLFW_COCOA", None);
+                sources.extend([
+                    "cocoa_init.m",
+                    "cocoa_joystick.m",
+                    "cocoa_monitor.m",
+                    "cocoa_window.m",
+                    "nsgl_context.m",
+                ]);
+            }
+            "x11" => {
+                build.define("_GLFW_X11", None);
+                sources.extend([
+                    "x11_init.c",
+                    "x11_monitor.c",
+                    "x11_window.c",
+                    "xkb_unicode.c",
+                    "glx_context.c",
+                ]);
+            }
+            "wayland" => {
+                build.define("_GLFW_WAYLAND", None);
+                sources.extend(["wl_init.c", "wl_monitor.c", "wl_window.c", "xkb_unicode.c"]);
+                let protocol_dir = std::path::Path::new(out_dir).join("wayland-protocols");
+                // When cross compiling, pkg-config would find the scanner of the target.
+                let use_pkg_config = cross.is_none() && !env_overrides.no_pkg_config;
+                generate_wayland_protocols(&protocol_dir, use_pkg_config);
+                build.include(protocol_dir);
+            }
+            _ => {}
+        }
+    }
+    if features.x11 || features.wayland {
+        sources.push("posix_poll.c");
+        if features.os == TargetOs::Linux {
+            sources.push("linux_joystick.c");
+        }
+    }
+    // xkb_unicode.c is shared by x11 and wayland.
+    sources.sort_unstable();
+    sources.dedup();
+    // pkg-config only for the include paths. main links the libs.
+    // With GLFW_NO_PKG_CONFIG, the headers must be in the compiler's default include paths.
+    if !env_overrides.no_pkg_config {
+        for lib in features.system_libs() {
+            let lib = probe_system_lib(pkg_config::Config::new().cargo_metadata(false), lib, cross);
+            build.includes(lib.include_paths);
+        }
+    }
+    build.files(sources.iter().map(|source| src_dir.join(source)));
+    build.compile("glfw3");
+    println!("cargo:rustc-link-search=native={out_dir}");
+    LibInfo::from_dirs(glfw_dir.join("include"), std::path::PathBuf::from(out_dir))
+}
+
+/// Runs `wayland-scanner` on `glfw/deps/wayland/*.xml`, to generate the
+/// `<name>-client-protocol.h` and `<name>-client-protocol-code.h` headers that glfw includes.
+///
+/// The scanner is `WAYLAND_SCANNER` if set, or the one from pkg-config (if `use_pkg_config`),
+/// or `wayland-scanner` in `PATH`.
+#[cfg(feature = "src-build-cc")]
+fn generate_wayland_protocols(protocol_dir: &std::path::Path, use_pkg_config: bool) {
+    println!("cargo:rerun-if-env-changed=WAYLAND_SCANNER");
+    let scanner = std::env::var("WAYLAND_SCANNER")
+        .ok()
+        .filter(|scanner| !scanner.is_empty())
+        .or_else(|| {
+            use_pkg_config
+                .then(|| pkg_config::get_variable("wayland-scanner", "wayland_scanner").ok())
+                .flatten()
+        })
+        .filter(|scanner| !scanner.is_empty())
+        .unwrap_or_else(|| "wayland-scanner".to_string());
+    std::fs::create_dir_all(protocol_dir).expect("failed to create wayland protocol dir");
+    let xml_dir = std::path::Path::new("./glfw/deps/wayland");
+    println!("cargo:rerun-if-changed={}", xml_dir.display());
+    let protocols = std::fs::read_dir(xml_dir).expect("failed to read glfw/deps/wayland");
+    for protocol in protocols {
+        let path = protocol.expect("failed to read glfw/deps/wayland").path();
+        if path.extension().is_none_or(|extension| extension != "xml") {
+            continue;
+        }
+        let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+        for (kind, suffix) in [
+            ("client-header", "-client-protocol.h"),
+            ("private-code", "-client-protocol-code.h"),
+        ] {
+            let output = protocol_dir.join(format!("{name}{suffix}"));
+            let status = std::process::Command::new(&scanner)
+                .arg(kind)
+                .arg(&path)
+                .arg(&output)
+                .status()
+                .unwrap_or_else(|e| {
+                    panic!("failed to run {scanner}: {e}. install wayland-scanner or set WAYLAND_SCANNER")
+                });
+            assert!(
+                status.success(),
+                "{scanner} {kind} {} failed with {status}",
+                path.display()
+            );
+        }
+    }
 }
 
 /// Generates bindings using bindgen
 /// feature-gated to make bindgen crate optional
+///
+/// If `GLFW_BINDGEN_3_3_HEADER` is set to the `glfw3.h` of a glfw 3.3 release, we also
+/// generate `bindings_3_3.rs` from it, so that tests/binding_drift.rs can check
+/// `src/sys/pregenerated_3_3.rs` too.
 #[cfg(feature = "bindgen")]
 fn generate_bindings(features: Features, out_dir: &str) {
-    // first, add glfw header.
-    let glfw_header = include_str!("./glfw/include/GLFW/glfw3.h");
+    generate_bindings_from(
+        features,
+        include_str!("./glfw/include/GLFW/glfw3.h"),
+        &format!("{out_dir}/bindings.rs"),
+    );
+    println!("cargo:rerun-if-env-changed=GLFW_BINDGEN_3_3_HEADER");
+    if let Some(header) =
+        std::env::var_os("GLFW_BINDGEN_3_3_HEADER").filter(|header| !header.is_empty())
+    {
+        let header = std::path::PathBuf::from(header);
+        println!("cargo:rerun-if-changed={}", header.display());
+        let glfw_header = std::fs::read_to_string(&header)
+            .unwrap_or_else(|e| panic!("failed to read GLFW_BINDGEN_3_3_HEADER {header:?}: {e}"));
+        // pregenerated_3_3.rs has no native functions. Those are in manual.rs
+        generate_bindings_from(
+            Features {
+                native: false,
+                ..features
+            },
+            &glfw_header,
+            &format!("{out_dir}/bindings_3_3.rs"),
+        );
+        println!("cargo:rustc-cfg=glfw_bindgen_3_3");
+    }
+}
+/// Generates bindings for `glfw_header` (and the native header, if enabled) into `output`.
+#[cfg(feature = "bindgen")]
+fn generate_bindings_from(features: Features, glfw_header: &str, output: &str) {
     let mut bindings = bindgen::Builder::default();
 
     if features.vulkan {
@@ -288,10 +1020,11 @@ fn generate_bindings(features: Features, out_dir: &str) {
         }
     }
     // if vulkan enabled, add GLFW_INCLUDE_VULKAN to generate vk-related bindings.
-    let vulkan_include = features
-        .vulkan
-        .then_some("#define GLFW_INCLUDE_VULKAN\n")
-        .unwrap_or_default();
+    let vulkan_include = if features.vulkan {
+        "#define GLFW_INCLUDE_VULKAN\n"
+    } else {
+        ""
+    };
 
     let mut native_include = "".to_string();
     // load glfw native header (iff native_* features are enabled)
@@ -338,9 +1071,8 @@ fn generate_bindings(features: Features, out_dir: &str) {
                         native_include.push_str("#define GLFW_EXPOSE_NATIVE_GLX\n");
                     }
                 }
-                // seriously, wtf is this...
                 if features.osmesa {
-                    native_include.push_str("\n#define GLFW_EXPOSE_NATIVE_OS_MESA\n");
+                    native_include.push_str("\n#define GLFW_EXPOSE_NATIVE_OSMESA\n");
                 }
             }
             TargetOs::Emscripten => {}
@@ -390,11 +1122,29 @@ fn generate_bindings(features: Features, out_dir: &str) {
     bindings
         .generate()
         .expect("failed to generate bindings")
-        .write_to_file(format!("{out_dir}/bindings.rs"))
-        .expect("failed to write bindings to out_dir/bindings.rs");
+        .write_to_file(output)
+        .unwrap_or_else(|e| panic!("failed to write bindings to {output}: {e}"));
 }
-/// Download prebuilt libraries
-fn download_libs(features: Features, out_dir: &str) {
+/// SHA-256 digests of the official prebuilt zips (from <https://github.com/glfw/glfw/releases/>).
+/// Every archive is verified against these before extracting.
+///
+/// When updating glfw version, add the digests of the new zips here (`sha256sum glfw-*.zip`).
+/// Archives without a pinned digest are rejected, unless `GLFW_PREBUILT_SHA256` is set.
+///
+/// TODO: pin `glfw-3.4.bin.WIN32.zip`, `glfw-3.4.bin.WIN64.zip` and `glfw-3.4.bin.MACOS.zip`.
+/// Until then, `prebuilt-libs` builds need `GLFW_PREBUILT_SHA256`.
+#[cfg(feature = "prebuilt-libs")]
+const PREBUILT_SHA256: &[(&str, &str)] = &[];
+
+/// Download prebuilt libraries (or use a cached/user provided archive) and extract them.
+///
+/// These env vars allow offline builds:
+/// - `GLFW_PREBUILT_ARCHIVE` - path to the zip to use. We never download in this case.
+/// - `GLFW_PREBUILT_CACHE_DIR` - directory to look for the zip (and to download it into).
+///   defaults to `OUT_DIR`.
+/// - `GLFW_PREBUILT_SHA256` - expected digest of the zip, if it isn't pinned in [`PREBUILT_SHA256`].
+#[cfg(feature = "prebuilt-libs")]
+fn download_libs(features: Features, out_dir: &str) -> LibInfo {
     const URL: &str = "https://github.com/glfw/glfw/releases/download/3.4";
     let zip_name: &str = match features.os {
         TargetOs::Win => {
@@ -411,41 +1161,68 @@ fn download_libs(features: Features, out_dir: &str) {
             unimplemented!("prebuilt libs not available for this OS");
         }
     };
-    let url = format!("{}/{}.zip", URL, zip_name);
-    let curl_status = std::process::Command::new("curl")
-        .current_dir(out_dir)
-        .args(["--progress-bar", "--fail", "-L", &url, "-o", "glfw.zip"])
-        .status();
+    let file_name = format!("{zip_name}.zip");
+    let env_path = |name: &str| {
+        println!("cargo:rerun-if-env-changed={name}");
+        std::env::var_os(name)
+            .filter(|value| !value.is_empty())
+            .map(std::path::PathBuf::from)
+    };
+    // pinned digests win. GLFW_PREBUILT_SHA256 is only for zips that we don't know.
+    let expected_digest = {
+        println!("cargo:rerun-if-env-changed=GLFW_PREBUILT_SHA256");
+        PREBUILT_SHA256
+            .iter()
+            .find(|(name, _)| *name == file_name)
+            .map(|(_, digest)| digest.to_string())
+            .or_else(|| std::env::var("GLFW_PREBUILT_SHA256").ok())
+            .map(|digest| digest.trim().to_lowercase())
+    };
 
-    assert!(
-        curl_status.expect("failed to run curl command").success(),
-        "curl failed to download {url} and store it in {out_dir:?}"
-    );
-    println!("downloaded impeller library from {url} and stored it in {out_dir:?}");
-    let mut command = if cfg!(unix) {
-        std::process::Command::new("unzip")
+    let archive = if let Some(archive) = env_path("GLFW_PREBUILT_ARCHIVE") {
+        assert!(
+            archive.exists(),
+            "GLFW_PREBUILT_ARCHIVE {archive:?} doesn't exist"
+        );
+        verify_archive(&archive, expected_digest.as_deref());
+        archive
     } else {
-        let mut command = std::process::Command::new("tar");
-        command.arg("-xvf");
-        command
+        let cache_dir = env_path("GLFW_PREBUILT_CACHE_DIR").unwrap_or_else(|| out_dir.into());
+        std::fs::create_dir_all(&cache_dir)
+            .unwrap_or_else(|e| panic!("failed to create cache dir {cache_dir:?}: {e}"));
+        let archive = cache_dir.join(&file_name);
+        if archive.exists() {
+            println!("using cached glfw archive {archive:?}");
+        } else {
+            let url = format!("{URL}/{file_name}");
+            // download to a temporary file, so that an interrupted download doesn't poison the cache.
+            let partial = cache_dir.join(format!("{file_name}.part"));
+            let curl_status = std::process::Command::new("curl")
+                .args(["--progress-bar", "--fail", "-L", &url, "-o"])
+                .arg(&partial)
+                .status();
+            assert!(
+                curl_status.expect("failed to run curl command").success(),
+                "curl failed to download {url} and store it in {cache_dir:?}"
+            );
+            std::fs::rename(&partial, &archive)
+                .unwrap_or_else(|e| panic!("failed to move {partial:?} to {archive:?}: {e}"));
+            println!("downloaded glfw library from {url} and stored it in {archive:?}");
+        }
+        verify_archive(&archive, expected_digest.as_deref());
+        archive
     };
-    let tar_status = command.arg("glfw.zip").current_dir(out_dir).status();
-    assert!(
-        tar_status
-            .expect("failed to run tar/unzip command")
-            .success(),
-        "tar failed to extract zip and store it in {out_dir:?}"
-    );
+    let zip_file =
+        std::fs::File::open(&archive).unwrap_or_else(|e| panic!("failed to open {archive:?}: {e}"));
+    zip::ZipArchive::new(zip_file)
+        .and_then(|mut zip| zip.extract(out_dir))
+        .unwrap_or_else(|e| panic!("failed to extract {archive:?} into {out_dir:?}: {e}"));
     println!("extracted glfw library from zip and stored it in {out_dir:?}");
     let lib_dir = std::path::Path::new(out_dir).join(zip_name);
     println!("cargo:THIRD_PARTY={}", lib_dir.display());
-    match features.os {
-        TargetOs::Win => {
-            println!(
-                "cargo:rustc-link-search=native={}",
-                lib_dir.join("lib-vc2022").display()
-            );
-        }
+    let include_dir = lib_dir.join("include");
+    let lib_dir = match features.os {
+        TargetOs::Win => lib_dir.join("lib-vc2022"),
         TargetOs::Mac => {
             let lib_dir = lib_dir.join("lib-universal");
             // hack because mac fails to recognize libglfw.3.dylib with -lglfw flag
@@ -454,10 +1231,35 @@ fn download_libs(features: Features, out_dir: &str) {
                 lib_dir.join("libglfw.dylib"),
             )
             .expect("failed to copy libglfw.3.dylib to libglfw.dylib");
-            println!("cargo:rustc-link-search=native={}", lib_dir.display());
+            lib_dir
         }
         _ => {
             unimplemented!()
         }
-    }
+    };
+    println!("cargo:rustc-link-search=native={}", lib_dir.display());
+    LibInfo::from_dirs(include_dir, lib_dir)
+}
+/// Checks the SHA-256 digest of the prebuilt archive.
+///
+/// We never extract an archive that we can't verify. If we don't know what to expect,
+/// we fail with the digest of the archive, so that it can be checked and pinned.
+#[cfg(feature = "prebuilt-libs")]
+fn verify_archive(archive: &std::path::Path, expected: Option<&str>) {
+    use sha2::Digest;
+    let bytes =
+        std::fs::read(archive).unwrap_or_else(|e| panic!("failed to read {archive:?}: {e}"));
+    let digest: String = sha2::Sha256::digest(&bytes)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+    let Some(expected) = expected else {
+        panic!(
+            "no pinned SHA-256 digest for {archive:?} (its digest is {digest}). Check it against the official release and set GLFW_PREBUILT_SHA256 to use it."
+        );
+    };
+    assert_eq!(
+        expected, digest,
+        "SHA-256 mismatch for {archive:?}. Delete it if it is a corrupt download."
+    );
 }
@@ -41,21 +41,10 @@ use glow::HasContext;
 
 fn main() {
     use glfw_sys::*;
-    use std::ffi::CStr;
 
     unsafe {
-        let mut description: *const i8 = std::ptr::null();
-
         if glfwInit() != GLFW_TRUE {
-            glfwGetError(&mut description);
-
-            panic!(
-                "Error: {:?}\n",
-                description
-                    .is_null()
-                    .then_some(c"")
-                    .unwrap_or_else(|| CStr::from_ptr(description))
-            );
+            panic!("Error: {:?}\n", glfw_sys::error::get_error());
         }
         println!("Initialized GLFW");
         let win = glfwCreateWindow(
@@ -66,15 +55,7 @@ fn main() {
             std::ptr::null_mut(),
         );
         if win.is_null() {
-            glfwGetError(&mut description);
-
-            println!(
-                "Error: {:?}\n",
-                description
-                    .is_null()
-                    .then_some(c"")
-                    .unwrap_or_else(|| CStr::from_ptr(description))
-            );
+            println!("Error: {:?}\n", glfw_sys::error::get_error());
             glfwTerminate();
             panic!();
         }
@@ -0,0 +1,20 @@
+/// Loads glfw at runtime and prints a proper error if it is not installed.
+///
+/// `cargo run --example=dlopen --features=dlopen`
+fn main() {
+    let api = match unsafe { glfw_sys::dynamic::GlfwApi::load() } {
+        Ok(api) => api,
+        Err(e) => {
+            eprintln!("failed to load glfw library: {e}");
+            std::process::exit(1);
+        }
+    };
+    for symbol in api.missing_symbols() {
+        println!("glfw library is missing {symbol}");
+    }
+    let check = glfw_sys::version::check_version_with(&api).expect("not a glfw 3 library");
+    println!("GLFW version {}", check.runtime);
+    if !check.is_compatible() {
+        println!("the bindings are for glfw {}", check.compiled);
+    }
+}
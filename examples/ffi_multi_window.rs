@@ -26,13 +26,11 @@ use glow::HasContext;
 //========================================================================
 fn main() {
     use glfw_sys::*;
-    use std::ffi::CStr;
 
     unsafe {
         let mut xpos = 0i32;
         let mut ypos = 0i32;
         let mut height = 0i32;
-        let mut description: *const i8 = std::ptr::null();
         let mut windows: [(*mut GLFWwindow, Option<glow::Context>); 4] = [
             (std::ptr::null_mut(), None),
             (std::ptr::null_mut(), None),
@@ -41,15 +39,7 @@ fn main() {
         ];
 
         if glfwInit() != GLFW_TRUE {
-            glfwGetError(&mut description);
-
-            panic!(
-                "Error: {:?}\n",
-                description
-                    .is_null()
-                    .then_some(c"")
-                    .unwrap_or_else(|| CStr::from_ptr(description))
-            );
+            panic!("Error: {:?}\n", glfw_sys::error::get_error());
         }
 
         glfwWindowHint(GLFW_DECORATED, GLFW_FALSE);
@@ -96,8 +86,12 @@ fn main() {
                 glfwWindowHint(GLFW_FOCUS_ON_SHOW, GLFW_FALSE);
             }
 
-            glfwWindowHint(GLFW_POSITION_X, xpos + size * (1 + (i & 1) as i32));
-            glfwWindowHint(GLFW_POSITION_Y, ypos + size * (1 + (i >> 1) as i32));
+            // position hints were added in glfw 3.4
+            #[cfg(glfw_3_4)]
+            {
+                glfwWindowHint(GLFW_POSITION_X, xpos + size * (1 + (i & 1) as i32));
+                glfwWindowHint(GLFW_POSITION_Y, ypos + size * (1 + (i >> 1) as i32));
+            }
 
             let win = glfwCreateWindow(
                 size,
@@ -107,15 +101,7 @@ fn main() {
                 std::ptr::null_mut(),
             );
             if win.is_null() {
-                glfwGetError(&mut description);
-
-                println!(
-                    "Error: {:?}\n",
-                    description
-                        .is_null()
-                        .then_some(c"")
-                        .unwrap_or_else(|| CStr::from_ptr(description))
-                );
+                println!("Error: {:?}\n", glfw_sys::error::get_error());
                 glfwTerminate();
                 panic!();
             }
@@ -2,6 +2,7 @@
 set -eoux pipefail
 
 # Usage example: `./gen_bindings.sh src/sys/pregenerated.rs`
+# For the glfw 3.3 bindings: `./gen_bindings.sh src/sys/pregenerated_3_3.rs /path/to/glfw-3.3/include/GLFW/glfw3.h`
 OUTPUT_PATH="$1"
 
 # Need to disable all these lints or clippy will complain.
@@ -13,7 +14,7 @@ PREPEND="#![allow(unused)]
 #![allow(rustdoc::invalid_rust_codeblocks)]
 #![allow(rustdoc::broken_intra_doc_links)]"
 
-HEADER_PATH="./glfw/include/GLFW/glfw3.h"
+HEADER_PATH="${2:-./glfw/include/GLFW/glfw3.h}"
 if [ ! -f $HEADER_PATH ]; then
     echo "cannot find $HEADER_PATH"
 fi
@@ -0,0 +1,195 @@
+//! A [`GLFWallocator`] that routes glfw's allocations to Rust's global allocator.
+//!
+//! glfw's `deallocate` doesn't pass the size of the block, so every block starts with a small
+//! header that stores its size. The allocations are counted per operation in
+//! [`AllocationCounters`](crate::allocator::AllocationCounters), to attribute memory to glfw.
+//!
+//! ```no_run
+//! use glfw_sys::allocator::*;
+//! unsafe {
+//!     init_rust_allocator();
+//!     assert_eq!(glfw_sys::glfwInit(), glfw_sys::GLFW_TRUE);
+//! }
+//! println!("glfw is using {} bytes", GLFW_ALLOCATIONS.stats().live_bytes);
+//! ```
+use crate::*;
+use std::alloc::Layout;
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Alignment of the blocks, same as `malloc` on 64-bit platforms.
+const ALIGN: usize = 16;
+/// Size of the header that stores the size of the block. Keeps the block aligned to `ALIGN`.
+const HEADER: usize = ALIGN;
+
+/// Counts the allocations made by glfw through [`rust_allocator`].
+///
+/// All counters only grow, except `live_bytes`.
+#[derive(Debug, Default)]
+pub struct AllocationCounters {
+    allocations: AtomicUsize,
+    allocated_bytes: AtomicUsize,
+    reallocations: AtomicUsize,
+    reallocated_bytes: AtomicUsize,
+    deallocations: AtomicUsize,
+    deallocated_bytes: AtomicUsize,
+    live_bytes: AtomicUsize,
+    peak_bytes: AtomicUsize,
+}
+
+/// A snapshot of [`AllocationCounters`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocationStats {
+    /// Number of `allocate` calls.
+    pub allocations: usize,
+    /// Bytes requested by `allocate` calls.
+    pub allocated_bytes: usize,
+    /// Number of `reallocate` calls.
+    pub reallocations: usize,
+    /// Bytes requested by `reallocate` calls (the new sizes).
+    pub reallocated_bytes: usize,
+    /// Number of `deallocate` calls.
+    pub deallocations: usize,
+    /// Bytes freed by `deallocate` calls.
+    pub deallocated_bytes: usize,
+    /// Bytes currently allocated by glfw (excluding the headers).
+    pub live_bytes: usize,
+    /// Highest value of `live_bytes`.
+    pub peak_bytes: usize,
+}
+
+impl AllocationCounters {
+    pub const fn new() -> Self {
+        Self {
+            allocations: AtomicUsize::new(0),
+            allocated_bytes: AtomicUsize::new(0),
+            reallocations: AtomicUsize::new(0),
+            reallocated_bytes: AtomicUsize::new(0),
+            deallocations: AtomicUsize::new(0),
+            deallocated_bytes: AtomicUsize::new(0),
+            live_bytes: AtomicUsize::new(0),
+            peak_bytes: AtomicUsize::new(0),
+        }
+    }
+    pub fn stats(&self) -> AllocationStats {
+        AllocationStats {
+            allocations: self.allocations.load(Ordering::Relaxed),
+            allocated_bytes: self.allocated_bytes.load(Ordering::Relaxed),
+            reallocations: self.reallocations.load(Ordering::Relaxed),
+            reallocated_bytes: self.reallocated_bytes.load(Ordering::Relaxed),
+            deallocations: self.deallocations.load(Ordering::Relaxed),
+            deallocated_bytes: self.deallocated_bytes.load(Ordering::Relaxed),
+            live_bytes: self.live_bytes.load(Ordering::Relaxed),
+            peak_bytes: self.peak_bytes.load(Ordering::Relaxed),
+        }
+    }
+    fn grow(&self, bytes: usize) {
+        let live = self.live_bytes.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        self.peak_bytes.fetch_max(live, Ordering::Relaxed);
+    }
+    fn shrink(&self, bytes: usize) {
+        self.live_bytes.fetch_sub(bytes, Ordering::Relaxed);
+    }
+}
+
+/// The counters used by [`init_rust_allocator`].
+pub static GLFW_ALLOCATIONS: AllocationCounters = AllocationCounters::new();
+
+/// An allocator that uses Rust's global allocator, and counts the allocations in `counters`.
+///
+/// Pass it to `glfwInitAllocator` before `glfwInit` (glfw copies the struct).
+/// The blocks must not be freed by any other allocator, so don't switch allocators
+/// while glfw is initialized.
+pub fn rust_allocator(counters: &'static AllocationCounters) -> GLFWallocator {
+    GLFWallocator {
+        allocate: Some(allocate),
+        reallocate: Some(reallocate),
+        deallocate: Some(deallocate),
+        user: counters as *const AllocationCounters as *mut c_void,
+    }
+}
+
+/// Makes glfw use [`rust_allocator`] with the [`GLFW_ALLOCATIONS`] counters.
+///
+/// # Safety
+/// Same as `glfwInitAllocator`: must be called before `glfwInit`, from the main thread.
+pub unsafe fn init_rust_allocator() {
+    glfwInitAllocator(&rust_allocator(&GLFW_ALLOCATIONS));
+}
+
+fn layout(size: usize) -> Option<Layout> {
+    Layout::from_size_align(size.checked_add(HEADER)?, ALIGN).ok()
+}
+
+/// Safety: `user` must be the pointer from `rust_allocator`.
+unsafe fn counters<'a>(user: *mut c_void) -> &'a AllocationCounters {
+    &*(user as *const AllocationCounters)
+}
+
+/// Writes the size into the header of `base` and returns the block after it.
+unsafe fn block_from_base(base: *mut u8, size: usize) -> *mut c_void {
+    (base as *mut usize).write(size);
+    base.add(HEADER) as *mut c_void
+}
+
+/// Returns the start of the allocation of `block`, and the size stored in its header.
+unsafe fn base_from_block(block: *mut c_void) -> (*mut u8, usize) {
+    let base = (block as *mut u8).sub(HEADER);
+    (base, (base as *const usize).read())
+}
+
+unsafe extern "C" fn allocate(size: usize, user: *mut c_void) -> *mut c_void {
+    let Some(layout) = layout(size) else {
+        return std::ptr::null_mut();
+    };
+    let base = std::alloc::alloc(layout);
+    if base.is_null() {
+        return std::ptr::null_mut();
+    }
+    let counters = counters(user);
+    counters.allocations.fetch_add(1, Ordering::Relaxed);
+    counters.allocated_bytes.fetch_add(size, Ordering::Relaxed);
+    counters.grow(size);
+    block_from_base(base, size)
+}
+
+unsafe extern "C" fn reallocate(block: *mut c_void, size: usize, user: *mut c_void) -> *mut c_void {
+    // glfw handles these cases itself, but the allocator contract allows them.
+    if block.is_null() {
+        return allocate(size, user);
+    }
+    let (base, old_size) = base_from_block(block);
+    let Some(new_layout) = layout(size) else {
+        return std::ptr::null_mut();
+    };
+    let old_layout = layout(old_size).expect("the size was valid when it was allocated");
+    let new_base = std::alloc::realloc(base, old_layout, new_layout.size());
+    if new_base.is_null() {
+        return std::ptr::null_mut();
+    }
+    let counters = counters(user);
+    counters.reallocations.fetch_add(1, Ordering::Relaxed);
+    counters
+        .reallocated_bytes
+        .fetch_add(size, Ordering::Relaxed);
+    counters.shrink(old_size);
+    counters.grow(size);
+    block_from_base(new_base, size)
+}
+
+unsafe extern "C" fn deallocate(block: *mut c_void, user: *mut c_void) {
+    if block.is_null() {
+        return;
+    }
+    let (base, size) = base_from_block(block);
+    std::alloc::dealloc(
+        base,
+        layout(size).expect("the size was valid when it was allocated"),
+    );
+    let counters = counters(user);
+    counters.deallocations.fetch_add(1, Ordering::Relaxed);
+    counters
+        .deallocated_bytes
+        .fetch_add(size, Ordering::Relaxed);
+    counters.shrink(size);
+}
@@ -0,0 +1,336 @@
+//! Rust closures as glfw callbacks, without unwinding across C frames.
+//!
+//! glfw callbacks are `extern "C"` functions, and a panic must never unwind out of them.
+//! The setters here install a trampoline as the glfw callback, which calls your closure inside
+//! [`catch_unwind`](std::panic::catch_unwind). If the closure panics, the payload is stashed and re-raised by
+//! [`poll_events`](crate::callbacks::poll_events) (or [`resume_panic`](crate::callbacks::resume_panic))
+//! after glfw has returned. Until then, the callbacks on that thread are skipped.
+//!
+//! Window callbacks are stored in a box in the window user pointer, so don't use
+//! `glfwSetWindowUserPointer` on windows that use these callbacks. Call
+//! [`drop_window_callbacks`](crate::callbacks::drop_window_callbacks) before `glfwDestroyWindow`
+//! to free them. Monitor and joystick callbacks are global in glfw (they are not tied to a
+//! monitor or joystick), so their closures are stored in thread locals of the main thread instead.
+//!
+//! ```no_run
+//! use glfw_sys::callbacks::*;
+//! use glfw_sys::*;
+//! unsafe {
+//!     assert_eq!(glfwInit(), GLFW_TRUE);
+//!     let window = glfwCreateWindow(800, 600, c"callbacks".as_ptr(), std::ptr::null_mut(), std::ptr::null_mut());
+//!     set_key_callback(window, Some(Box::new(|window, key, _scancode, action, _mods| {
+//!         if key == GLFW_KEY_ESCAPE && action == GLFW_PRESS {
+//!             glfwSetWindowShouldClose(window, GLFW_TRUE);
+//!         }
+//!     })));
+//!     while glfwWindowShouldClose(window) == GLFW_FALSE {
+//!         // re-raises panics from the closures
+//!         poll_events();
+//!     }
+//!     drop_window_callbacks(window);
+//!     glfwDestroyWindow(window);
+//!     glfwTerminate();
+//! }
+//! ```
+use crate::*;
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::ffi::{c_char, c_int, c_uint};
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::sync::{Mutex, PoisonError};
+
+thread_local! {
+    /// payload of the first panic caught in a callback on this thread.
+    static PANIC: Cell<Option<Box<dyn Any + Send>>> = const { Cell::new(None) };
+}
+
+/// Runs `callback`, catching any panic. Skips it if a previous callback already panicked.
+fn guard(callback: impl FnOnce()) {
+    let pending = PANIC.take();
+    if pending.is_some() {
+        PANIC.set(pending);
+        return;
+    }
+    if let Err(payload) = catch_unwind(AssertUnwindSafe(callback)) {
+        PANIC.set(Some(payload));
+    }
+}
+
+/// Storage for one closure.
+///
+/// The trampolines take the closure out while it runs, so that it can call glfw functions
+/// that trigger other callbacks, or replace itself. The generation is bumped by every setter
+/// call, so that a closure that was replaced (or removed) while running is not restored.
+struct Slot<T> {
+    callback: Option<T>,
+    generation: u64,
+}
+impl<T> Slot<T> {
+    const fn new() -> Self {
+        Self {
+            callback: None,
+            generation: 0,
+        }
+    }
+    /// Sets the closure, returning the previous one.
+    fn replace(&mut self, callback: Option<T>) -> Option<T> {
+        self.generation = self.generation.wrapping_add(1);
+        std::mem::replace(&mut self.callback, callback)
+    }
+    /// Takes the closure out to call it, along with the current generation.
+    fn take(&mut self) -> Option<(T, u64)> {
+        self.callback
+            .take()
+            .map(|callback| (callback, self.generation))
+    }
+    /// Puts back a closure from [`Slot::take`], unless a setter was called since.
+    fn restore(&mut self, callback: T, generation: u64) {
+        if self.generation == generation {
+            self.callback = Some(callback);
+        }
+    }
+}
+impl<T> Default for Slot<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Re-raises the panic caught in a callback on this thread, if any.
+pub fn resume_panic() {
+    if let Some(payload) = PANIC.take() {
+        resume_unwind(payload);
+    }
+}
+
+/// `glfwPollEvents` followed by [`resume_panic`].
+///
+/// # Safety
+/// Same as `glfwPollEvents`.
+pub unsafe fn poll_events() {
+    glfwPollEvents();
+    resume_panic();
+}
+
+/// `glfwWaitEvents` followed by [`resume_panic`].
+///
+/// # Safety
+/// Same as `glfwWaitEvents`.
+pub unsafe fn wait_events() {
+    glfwWaitEvents();
+    resume_panic();
+}
+
+/// `glfwWaitEventsTimeout` followed by [`resume_panic`].
+///
+/// # Safety
+/// Same as `glfwWaitEventsTimeout`.
+pub unsafe fn wait_events_timeout(timeout: f64) {
+    glfwWaitEventsTimeout(timeout);
+    resume_panic();
+}
+
+/// Generates the closure storage, setters and trampolines for window callbacks.
+///
+/// Each entry is `field: Alias = setter => glfwSetXCallback(args)`, where `args` are
+/// the arguments of the glfw callback after the window.
+macro_rules! window_callbacks {
+    ($(
+        $(#[$doc:meta])*
+        $field:ident: $alias:ident = $setter:ident => $glfw_set:ident($($arg:ident: $ty:ty),*);
+    )*) => {
+        $(
+            $(#[$doc])*
+            pub type $alias = Box<dyn FnMut(*mut GLFWwindow $(, $ty)*)>;
+        )*
+
+        /// The closures of a window. Stored in its user pointer.
+        #[derive(Default)]
+        struct WindowCallbacks {
+            $($field: Slot<$alias>,)*
+        }
+
+        $(
+            #[doc = concat!("Sets (or removes, with `None`) the closure called by `", stringify!($glfw_set), "`.")]
+            ///
+            /// Returns the previous closure.
+            ///
+            /// # Safety
+            #[doc = concat!("Same as `", stringify!($glfw_set), "`. The closures live in the window user pointer,")]
+            /// so this takes over `glfwSetWindowUserPointer` of `window`. The user pointer must be null
+            /// or set by the setters in this module, and must not be changed until
+            /// [`drop_window_callbacks`] is called.
+            pub unsafe fn $setter(window: *mut GLFWwindow, callback: Option<$alias>) -> Option<$alias> {
+                unsafe extern "C" fn trampoline(window: *mut GLFWwindow $(, $arg: $ty)*) {
+                    let callbacks = glfwGetWindowUserPointer(window) as *mut WindowCallbacks;
+                    if callbacks.is_null() {
+                        return;
+                    }
+                    let Some((mut callback, generation)) = (*callbacks).$field.take() else {
+                        return;
+                    };
+                    guard(|| callback(window $(, $arg)*));
+                    let callbacks = glfwGetWindowUserPointer(window) as *mut WindowCallbacks;
+                    if !callbacks.is_null() {
+                        (*callbacks).$field.restore(callback, generation);
+                    }
+                }
+                $glfw_set(window, callback.is_some().then_some(trampoline as _));
+                let callbacks = window_callbacks(window);
+                (*callbacks).$field.replace(callback)
+            }
+        )*
+
+        /// Removes all the closures set by this module and frees their storage.
+        ///
+        /// Call this before `glfwDestroyWindow`, otherwise the closures are leaked.
+        ///
+        /// # Safety
+        /// `window` must be valid, must not be called from a callback of this window,
+        /// and the window user pointer must be null or set by the setters in this module.
+        pub unsafe fn drop_window_callbacks(window: *mut GLFWwindow) {
+            let callbacks = glfwGetWindowUserPointer(window) as *mut WindowCallbacks;
+            if callbacks.is_null() {
+                return;
+            }
+            $($glfw_set(window, None);)*
+            glfwSetWindowUserPointer(window, std::ptr::null_mut());
+            drop(Box::from_raw(callbacks));
+        }
+    };
+}
+
+/// Returns the closures of the window, creating them if needed.
+unsafe fn window_callbacks(window: *mut GLFWwindow) -> *mut WindowCallbacks {
+    let mut callbacks = glfwGetWindowUserPointer(window) as *mut WindowCallbacks;
+    if callbacks.is_null() {
+        callbacks = Box::into_raw(Box::<WindowCallbacks>::default());
+        glfwSetWindowUserPointer(window, callbacks.cast());
+    }
+    callbacks
+}
+
+window_callbacks! {
+    /// `GLFWwindowposfun`: window, xpos, ypos
+    pos: WindowPosCallback = set_window_pos_callback => glfwSetWindowPosCallback(xpos: c_int, ypos: c_int);
+    /// `GLFWwindowsizefun`: window, width, height
+    size: WindowSizeCallback = set_window_size_callback => glfwSetWindowSizeCallback(width: c_int, height: c_int);
+    /// `GLFWwindowclosefun`: window
+    close: WindowCloseCallback = set_window_close_callback => glfwSetWindowCloseCallback();
+    /// `GLFWwindowrefreshfun`: window
+    refresh: WindowRefreshCallback = set_window_refresh_callback => glfwSetWindowRefreshCallback();
+    /// `GLFWwindowfocusfun`: window, focused
+    focus: WindowFocusCallback = set_window_focus_callback => glfwSetWindowFocusCallback(focused: c_int);
+    /// `GLFWwindowiconifyfun`: window, iconified
+    iconify: WindowIconifyCallback = set_window_iconify_callback => glfwSetWindowIconifyCallback(iconified: c_int);
+    /// `GLFWwindowmaximizefun`: window, maximized
+    maximize: WindowMaximizeCallback = set_window_maximize_callback => glfwSetWindowMaximizeCallback(maximized: c_int);
+    /// `GLFWframebuffersizefun`: window, width, height
+    framebuffer_size: FramebufferSizeCallback = set_framebuffer_size_callback => glfwSetFramebufferSizeCallback(width: c_int, height: c_int);
+    /// `GLFWwindowcontentscalefun`: window, xscale, yscale
+    content_scale: WindowContentScaleCallback = set_window_content_scale_callback => glfwSetWindowContentScaleCallback(xscale: f32, yscale: f32);
+    /// `GLFWkeyfun`: window, key, scancode, action, mods
+    key: KeyCallback = set_key_callback => glfwSetKeyCallback(key: c_int, scancode: c_int, action: c_int, mods: c_int);
+    /// `GLFWcharfun`: window, codepoint
+    char: CharCallback = set_char_callback => glfwSetCharCallback(codepoint: c_uint);
+    /// `GLFWcharmodsfun`: window, codepoint, mods
+    char_mods: CharModsCallback = set_char_mods_callback => glfwSetCharModsCallback(codepoint: c_uint, mods: c_int);
+    /// `GLFWmousebuttonfun`: window, button, action, mods
+    mouse_button: MouseButtonCallback = set_mouse_button_callback => glfwSetMouseButtonCallback(button: c_int, action: c_int, mods: c_int);
+    /// `GLFWcursorposfun`: window, xpos, ypos
+    cursor_pos: CursorPosCallback = set_cursor_pos_callback => glfwSetCursorPosCallback(xpos: f64, ypos: f64);
+    /// `GLFWcursorenterfun`: window, entered
+    cursor_enter: CursorEnterCallback = set_cursor_enter_callback => glfwSetCursorEnterCallback(entered: c_int);
+    /// `GLFWscrollfun`: window, xoffset, yoffset
+    scroll: ScrollCallback = set_scroll_callback => glfwSetScrollCallback(xoffset: f64, yoffset: f64);
+    /// `GLFWdropfun`: window, path_count, paths
+    drop: DropCallback = set_drop_callback => glfwSetDropCallback(path_count: c_int, paths: *mut *const c_char);
+}
+
+/// `GLFWmonitorfun`: monitor, event
+pub type MonitorCallback = Box<dyn FnMut(*mut GLFWmonitor, c_int)>;
+/// `GLFWjoystickfun`: jid, event
+pub type JoystickCallback = Box<dyn FnMut(c_int, c_int)>;
+/// `GLFWerrorfun`: error_code, description
+///
+/// Errors can be reported on any thread, so this must be `Send`.
+pub type ErrorCallback = Box<dyn FnMut(c_int, *const c_char) + Send>;
+
+thread_local! {
+    static MONITOR_CALLBACK: RefCell<Slot<MonitorCallback>> = const { RefCell::new(Slot::new()) };
+    static JOYSTICK_CALLBACK: RefCell<Slot<JoystickCallback>> = const { RefCell::new(Slot::new()) };
+}
+static ERROR_CALLBACK: Mutex<Slot<ErrorCallback>> = Mutex::new(Slot::new());
+
+/// Sets (or removes, with `None`) the closure called by `glfwSetMonitorCallback`.
+///
+/// Returns the previous closure.
+///
+/// # Safety
+/// Same as `glfwSetMonitorCallback`, so this must be called on the main thread.
+/// The callback is global rather than per monitor, so the closure is stored in a thread local
+/// instead of `glfwSetMonitorUserPointer`. Events must be processed on the thread that set it,
+/// otherwise the closure is not called.
+pub unsafe fn set_monitor_callback(callback: Option<MonitorCallback>) -> Option<MonitorCallback> {
+    unsafe extern "C" fn trampoline(monitor: *mut GLFWmonitor, event: c_int) {
+        let Some((mut callback, generation)) = MONITOR_CALLBACK.with_borrow_mut(Slot::take) else {
+            return;
+        };
+        guard(|| callback(monitor, event));
+        MONITOR_CALLBACK.with_borrow_mut(|slot| slot.restore(callback, generation));
+    }
+    glfwSetMonitorCallback(callback.is_some().then_some(trampoline as _));
+    MONITOR_CALLBACK.with_borrow_mut(|slot| slot.replace(callback))
+}
+
+/// Sets (or removes, with `None`) the closure called by `glfwSetJoystickCallback`.
+///
+/// Returns the previous closure.
+///
+/// # Safety
+/// Same as `glfwSetJoystickCallback`, so this must be called on the main thread.
+/// The callback is global rather than per joystick, so the closure is stored in a thread local
+/// instead of `glfwSetJoystickUserPointer`. Events must be processed on the thread that set it,
+/// otherwise the closure is not called.
+pub unsafe fn set_joystick_callback(
+    callback: Option<JoystickCallback>,
+) -> Option<JoystickCallback> {
+    unsafe extern "C" fn trampoline(jid: c_int, event: c_int) {
+        let Some((mut callback, generation)) = JOYSTICK_CALLBACK.with_borrow_mut(Slot::take) else {
+            return;
+        };
+        guard(|| callback(jid, event));
+        JOYSTICK_CALLBACK.with_borrow_mut(|slot| slot.restore(callback, generation));
+    }
+    glfwSetJoystickCallback(callback.is_some().then_some(trampoline as _));
+    JOYSTICK_CALLBACK.with_borrow_mut(|slot| slot.replace(callback))
+}
+
+/// Sets (or removes, with `None`) the closure called by `glfwSetErrorCallback`.
+///
+/// Returns the previous closure. A panic is re-raised on the thread that reported the error.
+/// While the closure runs, errors reported by other threads are not forwarded to it.
+///
+/// # Safety
+/// Same as `glfwSetErrorCallback`.
+pub unsafe fn set_error_callback(callback: Option<ErrorCallback>) -> Option<ErrorCallback> {
+    unsafe extern "C" fn trampoline(code: c_int, description: *const c_char) {
+        let taken = ERROR_CALLBACK
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take();
+        let Some((mut callback, generation)) = taken else {
+            return;
+        };
+        guard(|| callback(code, description));
+        ERROR_CALLBACK
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .restore(callback, generation);
+    }
+    glfwSetErrorCallback(callback.is_some().then_some(trampoline as _));
+    ERROR_CALLBACK
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .replace(callback)
+}
@@ -0,0 +1,449 @@
+//! Runtime loading of the glfw shared library.
+//!
+//! With the `dlopen` feature, the build script doesn't link glfw at all. Instead, you load
+//! the library at runtime with [`GlfwApi::load`](crate::dynamic::GlfwApi::load) and call
+//! functions through the returned function pointers. This lets applications start
+//! (and report a proper error) on machines without glfw installed.
+//!
+//! Every function is an `Option`, so an older library (eg: glfw 3.3) can still be used
+//! with reduced functionality. Use [`GlfwApi::missing_symbols`](crate::dynamic::GlfwApi::missing_symbols)
+//! to see what is unavailable.
+//!
+//! ```no_run
+//! let api = unsafe { glfw_sys::dynamic::GlfwApi::load() }.expect("glfw is not installed");
+//! for symbol in api.missing_symbols() {
+//!     eprintln!("glfw library is missing {symbol}");
+//! }
+//! let init = api.glfwInit.expect("glfwInit is always available");
+//! assert_eq!(unsafe { init() }, glfw_sys::GLFW_TRUE);
+//! ```
+#![allow(
+    non_snake_case,
+    reason = "fields are named after the glfw functions they point to"
+)]
+
+use crate::*;
+use std::ffi::{c_char, c_int, c_uchar, c_void};
+
+/// The library names we try (in order) in [`GlfwApi::load`].
+#[cfg(target_os = "windows")]
+const LIBRARY_NAMES: &[&str] = &["glfw3.dll"];
+#[cfg(target_os = "macos")]
+const LIBRARY_NAMES: &[&str] = &["libglfw.3.dylib", "libglfw.dylib"];
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+const LIBRARY_NAMES: &[&str] = &["libglfw.so.3", "libglfw.so"];
+
+/// Generates [`GlfwApi`] from a list of function signatures.
+///
+/// The first list contains the functions that are always available.
+/// The rest are grouped by the same `cfg` used for them in `src/sys/manual.rs`.
+macro_rules! glfw_api {
+    (
+        $(fn $name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)?;)*
+        $(
+            #[cfg($cfg:meta)] {
+                $(fn $cfg_name:ident($($cfg_arg:ident: $cfg_ty:ty),* $(,)?) $(-> $cfg_ret:ty)?;)*
+            }
+        )*
+    ) => {
+        /// Function pointers to every glfw function, loaded at runtime.
+        ///
+        /// A field is `None` if the loaded library doesn't export that symbol.
+        pub struct GlfwApi {
+            $(pub $name: Option<unsafe extern "C" fn($($ty),*) $(-> $ret)?>,)*
+            $($(
+                #[cfg($cfg)]
+                pub $cfg_name: Option<unsafe extern "C" fn($($cfg_ty),*) $(-> $cfg_ret)?>,
+            )*)*
+            /// names of the symbols that were not found in the library.
+            missing: Vec<&'static str>,
+            /// The function pointers are only valid as long as the library is loaded.
+            _library: libloading::Library,
+        }
+
+        impl GlfwApi {
+            /// Loads every symbol from an already opened library.
+            ///
+            /// # Safety
+            /// The library must be a glfw library, as we trust that the symbols
+            /// have the signatures declared in this crate.
+            pub unsafe fn from_library(library: libloading::Library) -> Self {
+                let mut missing = Vec::new();
+                $(
+                    let $name = load_symbol(&library, stringify!($name), &mut missing);
+                )*
+                $($(
+                    #[cfg($cfg)]
+                    let $cfg_name = load_symbol(&library, stringify!($cfg_name), &mut missing);
+                )*)*
+                Self {
+                    $($name,)*
+                    $($(
+                        #[cfg($cfg)]
+                        $cfg_name,
+                    )*)*
+                    missing,
+                    _library: library,
+                }
+            }
+        }
+
+        // Make sure that the signatures above match the extern declarations.
+        // bindgen may generate different (but compatible) types for native handles, so,
+        // we only check against our own bindings.
+        #[cfg(not(feature = "bindgen"))]
+        const _: () = {
+            $(let _: unsafe extern "C" fn($($ty),*) $(-> $ret)? = crate::sys::$name;)*
+            $($(
+                #[cfg($cfg)]
+                let _: unsafe extern "C" fn($($cfg_ty),*) $(-> $cfg_ret)? = crate::sys::$cfg_name;
+            )*)*
+        };
+    };
+}
+
+impl GlfwApi {
+    /// Loads the glfw shared library using the platform's default library name.
+    /// (eg: `libglfw.so.3` on linux or `glfw3.dll` on windows).
+    ///
+    /// Missing symbols are not an error. See [`Self::missing_symbols`].
+    ///
+    /// # Safety
+    /// Loading a library runs its initialization routines. See [`libloading::Library::new`].
+    pub unsafe fn load() -> Result<Self, libloading::Error> {
+        // report the error for the preferred name, as that is the one users should install.
+        let mut first_error = None;
+        for name in LIBRARY_NAMES {
+            match Self::load_from(name) {
+                Ok(api) => return Ok(api),
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+        Err(first_error.expect("there is atleast one library name"))
+    }
+    /// Loads the glfw shared library from the given path or library name.
+    ///
+    /// # Safety
+    /// Same as [`Self::load`], and the library at `path` must be a glfw library.
+    pub unsafe fn load_from(path: impl AsRef<std::ffi::OsStr>) -> Result<Self, libloading::Error> {
+        let library = libloading::Library::new(path)?;
+        Ok(Self::from_library(library))
+    }
+    /// Names of the glfw functions that were not found in the loaded library.
+    ///
+    /// The corresponding fields are `None`.
+    pub fn missing_symbols(&self) -> &[&'static str] {
+        &self.missing
+    }
+}
+
+/// Looks up a single symbol, and records its name in `missing` if it doesn't exist.
+unsafe fn load_symbol<T: Copy>(
+    library: &libloading::Library,
+    name: &'static str,
+    missing: &mut Vec<&'static str>,
+) -> Option<T> {
+    match library.get::<T>(name.as_bytes()) {
+        Ok(symbol) => Some(*symbol),
+        Err(_) => {
+            missing.push(name);
+            None
+        }
+    }
+}
+
+glfw_api! {
+    fn glfwInit() -> c_int;
+    fn glfwTerminate();
+    fn glfwInitHint(hint: c_int, value: c_int);
+    fn glfwInitAllocator(allocator: *const GLFWallocator);
+    fn glfwGetVersion(major: *mut c_int, minor: *mut c_int, rev: *mut c_int);
+    fn glfwGetVersionString() -> *const c_char;
+    fn glfwGetError(description: *mut *const c_char) -> c_int;
+    fn glfwSetErrorCallback(callback: GLFWerrorfun) -> GLFWerrorfun;
+    fn glfwGetPlatform() -> c_int;
+    fn glfwPlatformSupported(platform: c_int) -> c_int;
+    fn glfwGetMonitors(count: *mut c_int) -> *mut *mut GLFWmonitor;
+    fn glfwGetPrimaryMonitor() -> *mut GLFWmonitor;
+    fn glfwGetMonitorPos(monitor: *mut GLFWmonitor, xpos: *mut c_int, ypos: *mut c_int);
+    fn glfwGetMonitorWorkarea(
+        monitor: *mut GLFWmonitor,
+        xpos: *mut c_int,
+        ypos: *mut c_int,
+        width: *mut c_int,
+        height: *mut c_int,
+    );
+    fn glfwGetMonitorPhysicalSize(
+        monitor: *mut GLFWmonitor,
+        widthMM: *mut c_int,
+        heightMM: *mut c_int,
+    );
+    fn glfwGetMonitorContentScale(monitor: *mut GLFWmonitor, xscale: *mut f32, yscale: *mut f32);
+    fn glfwGetMonitorName(monitor: *mut GLFWmonitor) -> *const c_char;
+    fn glfwSetMonitorUserPointer(monitor: *mut GLFWmonitor, pointer: *mut c_void);
+    fn glfwGetMonitorUserPointer(monitor: *mut GLFWmonitor) -> *mut c_void;
+    fn glfwSetMonitorCallback(callback: GLFWmonitorfun) -> GLFWmonitorfun;
+    fn glfwGetVideoModes(monitor: *mut GLFWmonitor, count: *mut c_int) -> *const GLFWvidmode;
+    fn glfwGetVideoMode(monitor: *mut GLFWmonitor) -> *const GLFWvidmode;
+    fn glfwSetGamma(monitor: *mut GLFWmonitor, gamma: f32);
+    fn glfwGetGammaRamp(monitor: *mut GLFWmonitor) -> *const GLFWgammaramp;
+    fn glfwSetGammaRamp(monitor: *mut GLFWmonitor, ramp: *const GLFWgammaramp);
+    fn glfwDefaultWindowHints();
+    fn glfwWindowHint(hint: c_int, value: c_int);
+    fn glfwWindowHintString(hint: c_int, value: *const c_char);
+    fn glfwCreateWindow(
+        width: c_int,
+        height: c_int,
+        title: *const c_char,
+        monitor: *mut GLFWmonitor,
+        share: *mut GLFWwindow,
+    ) -> *mut GLFWwindow;
+    fn glfwDestroyWindow(window: *mut GLFWwindow);
+    fn glfwWindowShouldClose(window: *mut GLFWwindow) -> c_int;
+    fn glfwSetWindowShouldClose(window: *mut GLFWwindow, value: c_int);
+    fn glfwGetWindowTitle(window: *mut GLFWwindow) -> *const c_char;
+    fn glfwSetWindowTitle(window: *mut GLFWwindow, title: *const c_char);
+    fn glfwSetWindowIcon(window: *mut GLFWwindow, count: c_int, images: *const GLFWimage);
+    fn glfwGetWindowPos(window: *mut GLFWwindow, xpos: *mut c_int, ypos: *mut c_int);
+    fn glfwSetWindowPos(window: *mut GLFWwindow, xpos: c_int, ypos: c_int);
+    fn glfwGetWindowSize(window: *mut GLFWwindow, width: *mut c_int, height: *mut c_int);
+    fn glfwSetWindowSizeLimits(
+        window: *mut GLFWwindow,
+        minwidth: c_int,
+        minheight: c_int,
+        maxwidth: c_int,
+        maxheight: c_int,
+    );
+    fn glfwSetWindowAspectRatio(window: *mut GLFWwindow, numer: c_int, denom: c_int);
+    fn glfwSetWindowSize(window: *mut GLFWwindow, width: c_int, height: c_int);
+    fn glfwGetFramebufferSize(window: *mut GLFWwindow, width: *mut c_int, height: *mut c_int);
+    fn glfwGetWindowFrameSize(
+        window: *mut GLFWwindow,
+        left: *mut c_int,
+        top: *mut c_int,
+        right: *mut c_int,
+        bottom: *mut c_int,
+    );
+    fn glfwGetWindowContentScale(window: *mut GLFWwindow, xscale: *mut f32, yscale: *mut f32);
+    fn glfwGetWindowOpacity(window: *mut GLFWwindow) -> f32;
+    fn glfwSetWindowOpacity(window: *mut GLFWwindow, opacity: f32);
+    fn glfwIconifyWindow(window: *mut GLFWwindow);
+    fn glfwRestoreWindow(window: *mut GLFWwindow);
+    fn glfwMaximizeWindow(window: *mut GLFWwindow);
+    fn glfwShowWindow(window: *mut GLFWwindow);
+    fn glfwHideWindow(window: *mut GLFWwindow);
+    fn glfwFocusWindow(window: *mut GLFWwindow);
+    fn glfwRequestWindowAttention(window: *mut GLFWwindow);
+    fn glfwGetWindowMonitor(window: *mut GLFWwindow) -> *mut GLFWmonitor;
+    fn glfwSetWindowMonitor(
+        window: *mut GLFWwindow,
+        monitor: *mut GLFWmonitor,
+        xpos: c_int,
+        ypos: c_int,
+        width: c_int,
+        height: c_int,
+        refreshRate: c_int,
+    );
+    fn glfwGetWindowAttrib(window: *mut GLFWwindow, attrib: c_int) -> c_int;
+    fn glfwSetWindowAttrib(window: *mut GLFWwindow, attrib: c_int, value: c_int);
+    fn glfwSetWindowUserPointer(window: *mut GLFWwindow, pointer: *mut c_void);
+    fn glfwGetWindowUserPointer(window: *mut GLFWwindow) -> *mut c_void;
+    fn glfwSetWindowPosCallback(
+        window: *mut GLFWwindow,
+        callback: GLFWwindowposfun,
+    ) -> GLFWwindowposfun;
+    fn glfwSetWindowSizeCallback(
+        window: *mut GLFWwindow,
+        callback: GLFWwindowsizefun,
+    ) -> GLFWwindowsizefun;
+    fn glfwSetWindowCloseCallback(
+        window: *mut GLFWwindow,
+        callback: GLFWwindowclosefun,
+    ) -> GLFWwindowclosefun;
+    fn glfwSetWindowRefreshCallback(
+        window: *mut GLFWwindow,
+        callback: GLFWwindowrefreshfun,
+    ) -> GLFWwindowrefreshfun;
+    fn glfwSetWindowFocusCallback(
+        window: *mut GLFWwindow,
+        callback: GLFWwindowfocusfun,
+    ) -> GLFWwindowfocusfun;
+    fn glfwSetWindowIconifyCallback(
+        window: *mut GLFWwindow,
+        callback: GLFWwindowiconifyfun,
+    ) -> GLFWwindowiconifyfun;
+    fn glfwSetWindowMaximizeCallback(
+        window: *mut GLFWwindow,
+        callback: GLFWwindowmaximizefun,
+    ) -> GLFWwindowmaximizefun;
+    fn glfwSetFramebufferSizeCallback(
+        window: *mut GLFWwindow,
+        callback: GLFWframebuffersizefun,
+    ) -> GLFWframebuffersizefun;
+    fn glfwSetWindowContentScaleCallback(
+        window: *mut GLFWwindow,
+        callback: GLFWwindowcontentscalefun,
+    ) -> GLFWwindowcontentscalefun;
+    fn glfwPollEvents();
+    fn glfwWaitEvents();
+    fn glfwWaitEventsTimeout(timeout: f64);
+    fn glfwPostEmptyEvent();
+    fn glfwGetInputMode(window: *mut GLFWwindow, mode: c_int) -> c_int;
+    fn glfwSetInputMode(window: *mut GLFWwindow, mode: c_int, value: c_int);
+    fn glfwRawMouseMotionSupported() -> c_int;
+    fn glfwGetKeyName(key: c_int, scancode: c_int) -> *const c_char;
+    fn glfwGetKeyScancode(key: c_int) -> c_int;
+    fn glfwGetKey(window: *mut GLFWwindow, key: c_int) -> c_int;
+    fn glfwGetMouseButton(window: *mut GLFWwindow, button: c_int) -> c_int;
+    fn glfwGetCursorPos(window: *mut GLFWwindow, xpos: *mut f64, ypos: *mut f64);
+    fn glfwSetCursorPos(window: *mut GLFWwindow, xpos: f64, ypos: f64);
+    fn glfwCreateCursor(image: *const GLFWimage, xhot: c_int, yhot: c_int) -> *mut GLFWcursor;
+    fn glfwCreateStandardCursor(shape: c_int) -> *mut GLFWcursor;
+    fn glfwDestroyCursor(cursor: *mut GLFWcursor);
+    fn glfwSetCursor(window: *mut GLFWwindow, cursor: *mut GLFWcursor);
+    fn glfwSetKeyCallback(window: *mut GLFWwindow, callback: GLFWkeyfun) -> GLFWkeyfun;
+    fn glfwSetCharCallback(window: *mut GLFWwindow, callback: GLFWcharfun) -> GLFWcharfun;
+    fn glfwSetCharModsCallback(
+        window: *mut GLFWwindow,
+        callback: GLFWcharmodsfun,
+    ) -> GLFWcharmodsfun;
+    fn glfwSetMouseButtonCallback(
+        window: *mut GLFWwindow,
+        callback: GLFWmousebuttonfun,
+    ) -> GLFWmousebuttonfun;
+    fn glfwSetCursorPosCallback(
+        window: *mut GLFWwindow,
+        callback: GLFWcursorposfun,
+    ) -> GLFWcursorposfun;
+    fn glfwSetCursorEnterCallback(
+        window: *mut GLFWwindow,
+        callback: GLFWcursorenterfun,
+    ) -> GLFWcursorenterfun;
+    fn glfwSetScrollCallback(window: *mut GLFWwindow, callback: GLFWscrollfun) -> GLFWscrollfun;
+    fn glfwSetDropCallback(window: *mut GLFWwindow, callback: GLFWdropfun) -> GLFWdropfun;
+    fn glfwJoystickPresent(jid: c_int) -> c_int;
+    fn glfwGetJoystickAxes(jid: c_int, count: *mut c_int) -> *const f32;
+    fn glfwGetJoystickButtons(jid: c_int, count: *mut c_int) -> *const c_uchar;
+    fn glfwGetJoystickHats(jid: c_int, count: *mut c_int) -> *const c_uchar;
+    fn glfwGetJoystickName(jid: c_int) -> *const c_char;
+    fn glfwGetJoystickGUID(jid: c_int) -> *const c_char;
+    fn glfwSetJoystickUserPointer(jid: c_int, pointer: *mut c_void);
+    fn glfwGetJoystickUserPointer(jid: c_int) -> *mut c_void;
+    fn glfwJoystickIsGamepad(jid: c_int) -> c_int;
+    fn glfwSetJoystickCallback(callback: GLFWjoystickfun) -> GLFWjoystickfun;
+    fn glfwUpdateGamepadMappings(string: *const c_char) -> c_int;
+    fn glfwGetGamepadName(jid: c_int) -> *const c_char;
+    fn glfwGetGamepadState(jid: c_int, state: *mut GLFWgamepadstate) -> c_int;
+    fn glfwSetClipboardString(window: *mut GLFWwindow, string: *const c_char);
+    fn glfwGetClipboardString(window: *mut GLFWwindow) -> *const c_char;
+    fn glfwGetTime() -> f64;
+    fn glfwSetTime(time: f64);
+    fn glfwGetTimerValue() -> u64;
+    fn glfwGetTimerFrequency() -> u64;
+    fn glfwMakeContextCurrent(window: *mut GLFWwindow);
+    fn glfwGetCurrentContext() -> *mut GLFWwindow;
+    fn glfwSwapBuffers(window: *mut GLFWwindow);
+    fn glfwSwapInterval(interval: c_int);
+    fn glfwExtensionSupported(extension: *const c_char) -> c_int;
+    fn glfwGetProcAddress(procname: *const c_char) -> GLFWglproc;
+    fn glfwVulkanSupported() -> c_int;
+    fn glfwGetRequiredInstanceExtensions(count: *mut u32) -> *mut *const c_char;
+    #[cfg(any(not(feature = "bindgen"), feature = "vulkan"))] {
+        fn glfwInitVulkanLoader(loader: PFN_vkGetInstanceProcAddr);
+        fn glfwGetInstanceProcAddress(instance: VkInstance, procname: *const c_char) -> GLFWvkproc;
+        fn glfwGetPhysicalDevicePresentationSupport(
+            instance: VkInstance,
+            device: VkPhysicalDevice,
+            queuefamily: u32,
+        ) -> c_int;
+        fn glfwCreateWindowSurface(
+            instance: VkInstance,
+            window: *mut GLFWwindow,
+            allocator: *const VkAllocationCallbacks,
+            surface: *mut VkSurfaceKHR,
+        ) -> VkResult;
+    }
+    #[cfg(all(target_os = "windows", feature = "native-handles"))] {
+        fn glfwGetWin32Adapter(monitor: *mut GLFWmonitor) -> *const c_char;
+        fn glfwGetWin32Monitor(monitor: *mut GLFWmonitor) -> *const c_char;
+        fn glfwGetWin32Window(window: *mut GLFWwindow) -> *mut c_void;
+    }
+    #[cfg(all(target_os = "windows", feature = "native-gl"))] {
+        fn glfwGetWGLContext(window: *mut GLFWwindow) -> *mut c_void;
+    }
+    #[cfg(all(target_os = "macos", feature = "native-handles"))] {
+        fn glfwGetCocoaMonitor(monitor: *mut GLFWmonitor) -> u32;
+        fn glfwGetCocoaWindow(window: *mut GLFWwindow) -> *mut c_void;
+        fn glfwGetCocoaView(window: *mut GLFWwindow) -> *mut c_void;
+    }
+    #[cfg(all(target_os = "macos", feature = "native-gl"))] {
+        fn glfwGetNSGLContext(window: *mut GLFWwindow) -> *mut c_void;
+    }
+    #[cfg(all(
+        not(target_os = "macos"),
+        not(target_os = "windows"),
+        not(target_os = "emscripten"),
+        feature = "x11",
+        feature = "native-handles"
+    ))] {
+        fn glfwGetX11Display() -> *mut c_void;
+        fn glfwGetX11Adapter(monitor: *mut GLFWmonitor) -> usize;
+        fn glfwGetX11Monitor(monitor: *mut GLFWmonitor) -> usize;
+        fn glfwGetX11Window(window: *mut GLFWwindow) -> usize;
+        fn glfwSetX11SelectionString(string: *const c_char);
+        fn glfwGetX11SelectionString() -> *const c_char;
+    }
+    #[cfg(all(
+        not(target_os = "macos"),
+        not(target_os = "windows"),
+        not(target_os = "emscripten"),
+        feature = "x11",
+        feature = "native-gl"
+    ))] {
+        fn glfwGetGLXContext(window: *mut GLFWwindow) -> usize;
+        fn glfwGetGLXWindow(window: *mut GLFWwindow) -> usize;
+    }
+    #[cfg(all(
+        not(target_os = "macos"),
+        not(target_os = "windows"),
+        not(target_os = "emscripten"),
+        feature = "wayland",
+        feature = "native-handles"
+    ))] {
+        fn glfwGetWaylandDisplay() -> *const c_void;
+        fn glfwGetWaylandMonitor(monitor: *mut GLFWmonitor) -> *const c_void;
+        fn glfwGetWaylandWindow(window: *mut GLFWwindow) -> *mut c_void;
+    }
+    #[cfg(all(
+        not(target_os = "macos"),
+        not(target_os = "windows"),
+        not(target_os = "emscripten"),
+        any(
+            all(feature = "wayland", feature = "native-gl"),
+            feature = "native-egl"
+        )
+    ))] {
+        fn glfwGetEGLDisplay() -> *mut c_void;
+        fn glfwGetEGLContext(window: *mut GLFWwindow) -> *mut c_void;
+        fn glfwGetEGLSurface(window: *mut GLFWwindow) -> *mut c_void;
+    }
+    #[cfg(feature = "osmesa")] {
+        fn glfwGetOSMesaColorBuffer(
+            window: *mut GLFWwindow,
+            width: *mut c_int,
+            height: *mut c_int,
+            format: *mut c_int,
+            buffer: *mut *mut c_void,
+        ) -> c_int;
+        fn glfwGetOSMesaDepthBuffer(
+            window: *mut GLFWwindow,
+            width: *mut c_int,
+            height: *mut c_int,
+            bytesPerValue: *mut c_int,
+            buffer: *mut *mut c_void,
+        ) -> c_int;
+        fn glfwGetOSMesaContext(window: *mut GLFWwindow) -> *mut c_void;
+    }
+}
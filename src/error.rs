@@ -0,0 +1,157 @@
+//! Structured access to glfw errors.
+//!
+//! [`get_error`](crate::error::get_error) wraps `glfwGetError`, so you don't have to deal
+//! with the error code and the (possibly null) description pointer yourself.
+//!
+//! ```no_run
+//! use glfw_sys::*;
+//! if unsafe { glfwInit() } != GLFW_TRUE {
+//!     let error = glfw_sys::error::get_error().expect("glfwInit failed without an error");
+//!     panic!("failed to initialize glfw: {error}");
+//! }
+//! ```
+use crate::input::glfw_enum;
+use crate::*;
+use std::ffi::CStr;
+use std::fmt::{self, Display};
+
+glfw_enum! {
+    /// glfw error codes (excluding `GLFW_NO_ERROR`).
+    pub enum ErrorCode {
+        NotInitialized = GLFW_NOT_INITIALIZED => "NOT_INITIALIZED",
+        NoCurrentContext = GLFW_NO_CURRENT_CONTEXT => "NO_CURRENT_CONTEXT",
+        InvalidEnum = GLFW_INVALID_ENUM => "INVALID_ENUM",
+        InvalidValue = GLFW_INVALID_VALUE => "INVALID_VALUE",
+        OutOfMemory = GLFW_OUT_OF_MEMORY => "OUT_OF_MEMORY",
+        ApiUnavailable = GLFW_API_UNAVAILABLE => "API_UNAVAILABLE",
+        VersionUnavailable = GLFW_VERSION_UNAVAILABLE => "VERSION_UNAVAILABLE",
+        PlatformError = GLFW_PLATFORM_ERROR => "PLATFORM_ERROR",
+        FormatUnavailable = GLFW_FORMAT_UNAVAILABLE => "FORMAT_UNAVAILABLE",
+        NoWindowContext = GLFW_NO_WINDOW_CONTEXT => "NO_WINDOW_CONTEXT",
+        #[cfg(glfw_3_4)]
+        CursorUnavailable = GLFW_CURSOR_UNAVAILABLE => "CURSOR_UNAVAILABLE",
+        #[cfg(glfw_3_4)]
+        FeatureUnavailable = GLFW_FEATURE_UNAVAILABLE => "FEATURE_UNAVAILABLE",
+        #[cfg(glfw_3_4)]
+        FeatureUnimplemented = GLFW_FEATURE_UNIMPLEMENTED => "FEATURE_UNIMPLEMENTED",
+        #[cfg(glfw_3_4)]
+        PlatformUnavailable = GLFW_PLATFORM_UNAVAILABLE => "PLATFORM_UNAVAILABLE",
+    }
+}
+impl std::error::Error for ErrorCode {}
+
+/// An error reported by glfw.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlfwError {
+    pub code: ErrorCode,
+    /// The UTF-8 description provided by glfw. Empty if glfw didn't provide one.
+    pub description: String,
+}
+impl GlfwError {
+    /// Builds an error from the arguments of a `GLFWerrorfun` callback
+    /// (or the results of `glfwGetError`). Returns `None` for `GLFW_NO_ERROR`.
+    ///
+    /// Unknown codes (eg: added in a newer glfw version) are reported as [`ErrorCode::PlatformError`],
+    /// but the description is kept as is.
+    ///
+    /// # Safety
+    /// `description` must be null or a valid nul-terminated string.
+    pub unsafe fn from_raw(
+        code: std::ffi::c_int,
+        description: *const std::ffi::c_char,
+    ) -> Option<Self> {
+        if code == GLFW_NO_ERROR {
+            return None;
+        }
+        let description = if description.is_null() {
+            String::new()
+        } else {
+            CStr::from_ptr(description).to_string_lossy().into_owned()
+        };
+        Some(Self {
+            code: ErrorCode::try_from(code).unwrap_or(ErrorCode::PlatformError),
+            description,
+        })
+    }
+}
+impl Display for GlfwError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.description.is_empty() {
+            write!(f, "{}", self.code)
+        } else {
+            write!(f, "{}: {}", self.code, self.description)
+        }
+    }
+}
+impl std::error::Error for GlfwError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.code)
+    }
+}
+
+/// Returns (and clears) the last error that occurred on the calling thread, using `glfwGetError`.
+///
+/// This can be called before glfw is initialized and from any thread.
+pub fn get_error() -> Option<GlfwError> {
+    let mut description = std::ptr::null();
+    // Safety: glfwGetError can be called at any time, and the description is valid
+    // until the next glfw call, which is after we copied it.
+    unsafe {
+        let code = glfwGetError(&mut description);
+        GlfwError::from_raw(code, description)
+    }
+}
+
+/// The callback that was installed before [`set_error_logger`], which we chain to.
+#[cfg(any(feature = "log", feature = "tracing"))]
+static PREVIOUS_ERROR_CALLBACK: std::sync::Mutex<GLFWerrorfun> = std::sync::Mutex::new(None);
+
+/// Installs an error callback that forwards every glfw error to `tracing` (with `tracing` feature)
+/// or `log` (with `log` feature).
+///
+/// The errors are logged at error level with `glfw` target, the error code (eg: `PLATFORM_ERROR`)
+/// as the `code` field and the description as the message. If both features are enabled,
+/// only `tracing` is used.
+///
+/// Any previously installed callback keeps getting called after the error is logged.
+/// Returns that previous callback, like `glfwSetErrorCallback`.
+/// Calling this again doesn't chain the logger to itself.
+///
+/// # Safety
+/// Same as `glfwSetErrorCallback`: must be called from the main thread.
+#[cfg(any(feature = "log", feature = "tracing"))]
+pub unsafe fn set_error_logger() -> GLFWerrorfun {
+    use std::sync::PoisonError;
+    let mut chained = PREVIOUS_ERROR_CALLBACK
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    let previous = glfwSetErrorCallback(Some(log_error));
+    let is_logger = |callback: GLFWerrorfun| {
+        callback.is_some_and(|callback| {
+            std::ptr::fn_addr_eq(callback, log_error as unsafe extern "C" fn(_, _))
+        })
+    };
+    if is_logger(previous) {
+        // already installed, keep chaining to the callback from the first call.
+        *chained
+    } else {
+        *chained = previous;
+        previous
+    }
+}
+
+#[cfg(any(feature = "log", feature = "tracing"))]
+unsafe extern "C" fn log_error(code: std::ffi::c_int, description: *const std::ffi::c_char) {
+    if let Some(error) = GlfwError::from_raw(code, description) {
+        #[cfg(feature = "tracing")]
+        tracing::error!(target: "glfw", code = error.code.name(), "{}", error.description);
+        #[cfg(all(feature = "log", not(feature = "tracing")))]
+        log::error!(target: "glfw", code = error.code.name(); "{}", error.description);
+    }
+    let previous = *PREVIOUS_ERROR_CALLBACK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if let Some(previous) = previous {
+        previous(code, description);
+    }
+}
@@ -0,0 +1,266 @@
+//! Window events as a polled stream, instead of callbacks.
+//!
+//! [`EventQueue::new`](crate::events::EventQueue::new) registers every window callback
+//! (using the [`callbacks`](crate::callbacks) module), and each callback pushes a
+//! [`WindowEvent`](crate::events::WindowEvent) along with the `glfwGetTime` timestamp into
+//! the queue of that window. Drain it after `glfwPollEvents`/`glfwWaitEventsTimeout`.
+//!
+//! ```no_run
+//! use glfw_sys::events::{EventQueue, WindowEvent};
+//! use glfw_sys::*;
+//! unsafe {
+//!     assert_eq!(glfwInit(), GLFW_TRUE);
+//!     let window = glfwCreateWindow(800, 600, c"events".as_ptr(), std::ptr::null_mut(), std::ptr::null_mut());
+//!     let events = EventQueue::new(window);
+//!     'main: loop {
+//!         glfw_sys::callbacks::poll_events();
+//!         for (time, event) in events.drain() {
+//!             println!("{time:.3}: {event:?}");
+//!             if event == WindowEvent::Close {
+//!                 break 'main;
+//!             }
+//!         }
+//!     }
+//!     glfw_sys::callbacks::drop_window_callbacks(window);
+//!     glfwDestroyWindow(window);
+//!     glfwTerminate();
+//! }
+//! ```
+use crate::callbacks::*;
+use crate::input::{Action, Key, Modifiers, MouseButton};
+use crate::*;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::ffi::CStr;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+/// An event of a window, built from the arguments of the matching glfw callback.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WindowEvent {
+    Pos {
+        x: i32,
+        y: i32,
+    },
+    Size {
+        width: i32,
+        height: i32,
+    },
+    Close,
+    Refresh,
+    Focus(bool),
+    Iconify(bool),
+    Maximize(bool),
+    FramebufferSize {
+        width: i32,
+        height: i32,
+    },
+    ContentScale {
+        x: f32,
+        y: f32,
+    },
+    Key {
+        key: Key,
+        scancode: i32,
+        action: Action,
+        mods: Modifiers,
+    },
+    /// `None` if the codepoint is not a valid `char`.
+    Char(Option<char>),
+    CharModifiers {
+        character: Option<char>,
+        mods: Modifiers,
+    },
+    MouseButton {
+        button: MouseButton,
+        action: Action,
+        mods: Modifiers,
+    },
+    CursorPos {
+        x: f64,
+        y: f64,
+    },
+    CursorEnter(bool),
+    Scroll {
+        x: f64,
+        y: f64,
+    },
+    /// Paths that are not valid UTF-8 are converted lossily.
+    FileDrop(Vec<PathBuf>),
+}
+
+/// The events of a window, with the time (from `glfwGetTime`) at which they were received.
+///
+/// Cloning this gives another handle to the same queue.
+#[derive(Debug, Clone, Default)]
+pub struct EventQueue {
+    events: Rc<RefCell<VecDeque<(f64, WindowEvent)>>>,
+}
+impl EventQueue {
+    /// Registers all window callbacks of `window`, pushing their events into the returned queue.
+    ///
+    /// This replaces any closures previously set with the [`crate::callbacks`] module.
+    /// Call [`drop_window_callbacks`] before destroying the window.
+    ///
+    /// # Safety
+    /// Same as the setters in the [`crate::callbacks`] module.
+    pub unsafe fn new(window: *mut GLFWwindow) -> Self {
+        let queue = Self::default();
+        let events = queue.clone();
+        set_window_pos_callback(
+            window,
+            Some(Box::new(move |_, x, y| {
+                events.push(WindowEvent::Pos { x, y })
+            })),
+        );
+        let events = queue.clone();
+        set_window_size_callback(
+            window,
+            Some(Box::new(move |_, width, height| {
+                events.push(WindowEvent::Size { width, height })
+            })),
+        );
+        let events = queue.clone();
+        set_framebuffer_size_callback(
+            window,
+            Some(Box::new(move |_, width, height| {
+                events.push(WindowEvent::FramebufferSize { width, height })
+            })),
+        );
+        let events = queue.clone();
+        set_window_close_callback(
+            window,
+            Some(Box::new(move |_| events.push(WindowEvent::Close))),
+        );
+        let events = queue.clone();
+        set_window_refresh_callback(
+            window,
+            Some(Box::new(move |_| events.push(WindowEvent::Refresh))),
+        );
+        let events = queue.clone();
+        set_window_focus_callback(
+            window,
+            Some(Box::new(move |_, focused| {
+                events.push(WindowEvent::Focus(focused == GLFW_TRUE))
+            })),
+        );
+        let events = queue.clone();
+        set_window_iconify_callback(
+            window,
+            Some(Box::new(move |_, iconified| {
+                events.push(WindowEvent::Iconify(iconified == GLFW_TRUE))
+            })),
+        );
+        let events = queue.clone();
+        set_window_maximize_callback(
+            window,
+            Some(Box::new(move |_, maximized| {
+                events.push(WindowEvent::Maximize(maximized == GLFW_TRUE))
+            })),
+        );
+        let events = queue.clone();
+        set_window_content_scale_callback(
+            window,
+            Some(Box::new(move |_, x, y| {
+                events.push(WindowEvent::ContentScale { x, y })
+            })),
+        );
+        let events = queue.clone();
+        set_key_callback(
+            window,
+            Some(Box::new(move |_, key, scancode, action, mods| {
+                events.push(WindowEvent::Key {
+                    key: Key::try_from(key).unwrap_or(Key::Unknown),
+                    scancode,
+                    action: Action::try_from(action).expect("glfw reported an invalid action"),
+                    mods: Modifiers::from_bits_truncate(mods),
+                })
+            })),
+        );
+        let events = queue.clone();
+        set_char_callback(
+            window,
+            Some(Box::new(move |_, codepoint| {
+                events.push(WindowEvent::Char(char::from_u32(codepoint)))
+            })),
+        );
+        let events = queue.clone();
+        set_char_mods_callback(
+            window,
+            Some(Box::new(move |_, codepoint, mods| {
+                events.push(WindowEvent::CharModifiers {
+                    character: char::from_u32(codepoint),
+                    mods: Modifiers::from_bits_truncate(mods),
+                })
+            })),
+        );
+        let events = queue.clone();
+        set_mouse_button_callback(
+            window,
+            Some(Box::new(move |_, button, action, mods| {
+                events.push(WindowEvent::MouseButton {
+                    button: MouseButton::try_from(button)
+                        .expect("glfw reported an invalid mouse button"),
+                    action: Action::try_from(action).expect("glfw reported an invalid action"),
+                    mods: Modifiers::from_bits_truncate(mods),
+                })
+            })),
+        );
+        let events = queue.clone();
+        set_cursor_pos_callback(
+            window,
+            Some(Box::new(move |_, x, y| {
+                events.push(WindowEvent::CursorPos { x, y })
+            })),
+        );
+        let events = queue.clone();
+        set_cursor_enter_callback(
+            window,
+            Some(Box::new(move |_, entered| {
+                events.push(WindowEvent::CursorEnter(entered == GLFW_TRUE))
+            })),
+        );
+        let events = queue.clone();
+        set_scroll_callback(
+            window,
+            Some(Box::new(move |_, x, y| {
+                events.push(WindowEvent::Scroll { x, y })
+            })),
+        );
+        let events = queue.clone();
+        set_drop_callback(
+            window,
+            Some(Box::new(move |_, count, paths| {
+                let paths = (0..count.max(0) as usize)
+                    .map(|index| {
+                        // Safety: glfw passes `count` valid paths.
+                        let path = unsafe { CStr::from_ptr(*paths.add(index)) };
+                        PathBuf::from(path.to_string_lossy().into_owned())
+                    })
+                    .collect();
+                events.push(WindowEvent::FileDrop(paths))
+            })),
+        );
+        queue
+    }
+    fn push(&self, event: WindowEvent) {
+        // Safety: glfwGetTime can be called from any thread after init, and the
+        // callbacks are only called while glfw is initialized.
+        let time = unsafe { glfwGetTime() };
+        self.events.borrow_mut().push_back((time, event));
+    }
+    /// Removes and returns all the queued events, oldest first.
+    pub fn drain(&self) -> Vec<(f64, WindowEvent)> {
+        self.events.borrow_mut().drain(..).collect()
+    }
+    /// Removes and returns the oldest queued event.
+    pub fn pop(&self) -> Option<(f64, WindowEvent)> {
+        self.events.borrow_mut().pop_front()
+    }
+    pub fn len(&self) -> usize {
+        self.events.borrow().len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.events.borrow().is_empty()
+    }
+}
@@ -0,0 +1,300 @@
+//! Parsing and applying SDL gamepad mappings (the `gamecontrollerdb.txt` format).
+//!
+//! glfw ships with a built-in set of mappings, which lags behind new controllers.
+//! [`parse_mappings`](crate::gamepad::parse_mappings) validates mapping lines the same way glfw
+//! does (reporting the errors per line), and
+//! [`update_gamepad_mappings`](crate::gamepad::update_gamepad_mappings) hands them to
+//! `glfwUpdateGamepadMappings`.
+//!
+//! With the `gamecontrollerdb` feature, a snapshot of the community
+//! [SDL_GameControllerDB](https://github.com/mdqinc/SDL_GameControllerDB) is embedded in the crate
+//! and can be applied with `update_bundled_mappings`.
+//!
+//! ```no_run
+//! use glfw_sys::gamepad::*;
+//! let text = std::fs::read_to_string("gamecontrollerdb.txt").unwrap();
+//! let (mappings, errors) = parse_mappings(&text);
+//! for error in errors {
+//!     eprintln!("{error}");
+//! }
+//! let mappings: Vec<_> = mappings
+//!     .into_iter()
+//!     .filter(|mapping| mapping.is_for_platform(Platform::current()))
+//!     .collect();
+//! unsafe {
+//!     assert_eq!(glfw_sys::glfwInit(), glfw_sys::GLFW_TRUE);
+//!     update_gamepad_mappings(&mappings).unwrap();
+//! }
+//! ```
+use crate::error::{get_error, ErrorCode, GlfwError};
+use crate::*;
+use std::ffi::CString;
+use std::fmt::{self, Display};
+
+/// The community mappings database, embedded with the `gamecontrollerdb` feature.
+///
+/// Update it by replacing `gamecontrollerdb/gamecontrollerdb.txt` with a newer one from
+/// <https://github.com/mdqinc/SDL_GameControllerDB>.
+#[cfg(feature = "gamecontrollerdb")]
+pub const GAMECONTROLLERDB: &str = include_str!("../gamecontrollerdb/gamecontrollerdb.txt");
+
+/// Platforms as named in the `platform` field of SDL mappings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Platform {
+    Windows,
+    MacOs,
+    Linux,
+    Android,
+    Ios,
+}
+impl Platform {
+    /// All the platforms.
+    pub const ALL: &'static [Self] = &[
+        Self::Windows,
+        Self::MacOs,
+        Self::Linux,
+        Self::Android,
+        Self::Ios,
+    ];
+    /// The platform we are compiled for. glfw uses the linux mappings on other unix platforms too.
+    pub const fn current() -> Self {
+        if cfg!(target_os = "windows") {
+            Self::Windows
+        } else if cfg!(target_os = "macos") {
+            Self::MacOs
+        } else {
+            Self::Linux
+        }
+    }
+    /// The value of the `platform` field (eg: `Mac OS X`).
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Windows => "Windows",
+            Self::MacOs => "Mac OS X",
+            Self::Linux => "Linux",
+            Self::Android => "Android",
+            Self::Ios => "iOS",
+        }
+    }
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|platform| platform.name() == name)
+    }
+}
+impl Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A validated mapping line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mapping<'a> {
+    /// 32 hex digits that identify the controller.
+    pub guid: &'a str,
+    /// Human readable name of the controller.
+    pub name: &'a str,
+    /// `None` if the mapping doesn't have a `platform` field (applies to all platforms).
+    pub platform: Option<Platform>,
+    /// The whole mapping line, as passed to glfw.
+    pub line: &'a str,
+}
+impl<'a> Mapping<'a> {
+    /// Validates a single mapping line.
+    pub fn parse(line: &'a str) -> Result<Self, MappingError> {
+        let line = line.trim();
+        let mut fields = line.split(',');
+        let guid = fields.next().unwrap_or_default();
+        if guid.len() != 32 || !guid.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Err(MappingError::InvalidGuid(guid.to_string()));
+        }
+        let name = fields.next().ok_or(MappingError::MissingName)?;
+        // glfw stores the name in a 128 byte buffer (with the nul terminator)
+        if name.is_empty() || name.len() >= 128 || name.contains('\0') {
+            return Err(MappingError::InvalidName(name.to_string()));
+        }
+        let mut platform = None;
+        // the lines usually end with a comma, so the last field is empty.
+        for field in fields.filter(|field| !field.is_empty()) {
+            let (key, value) = field
+                .split_once(':')
+                .ok_or_else(|| MappingError::InvalidField(field.to_string()))?;
+            if key == "platform" {
+                platform = Some(
+                    Platform::from_name(value)
+                        .ok_or_else(|| MappingError::UnknownPlatform(value.to_string()))?,
+                );
+            } else if GAMEPAD_ELEMENTS.contains(&key.trim_start_matches(['+', '-']))
+                && !is_valid_source(value)
+            {
+                return Err(MappingError::InvalidField(field.to_string()));
+            }
+            // glfw ignores any other fields (eg: `misc1`, `paddle1`, `touchpad`)
+        }
+        Ok(Self {
+            guid,
+            name,
+            platform,
+            line,
+        })
+    }
+    /// Whether glfw would use this mapping on `platform`.
+    pub fn is_for_platform(&self, platform: Platform) -> bool {
+        self.platform
+            .is_none_or(|mapping_platform| mapping_platform == platform)
+    }
+}
+
+/// The gamepad elements that glfw maps.
+const GAMEPAD_ELEMENTS: &[&str] = &[
+    "a",
+    "b",
+    "x",
+    "y",
+    "back",
+    "start",
+    "guide",
+    "leftshoulder",
+    "rightshoulder",
+    "leftstick",
+    "rightstick",
+    "dpup",
+    "dpright",
+    "dpdown",
+    "dpleft",
+    "lefttrigger",
+    "righttrigger",
+    "leftx",
+    "lefty",
+    "rightx",
+    "righty",
+];
+
+/// Whether `value` is a joystick input: an axis (`a2`, `+a2`, `-a2`, `a2~`),
+/// a button (`b3`) or a hat bit (`h0.4`).
+fn is_valid_source(value: &str) -> bool {
+    let value = value.strip_prefix(['+', '-']).unwrap_or(value);
+    let is_index = |index: &str| !index.is_empty() && index.bytes().all(|b| b.is_ascii_digit());
+    if let Some(axis) = value.strip_prefix('a') {
+        is_index(axis.strip_suffix('~').unwrap_or(axis))
+    } else if let Some(button) = value.strip_prefix('b') {
+        is_index(button)
+    } else if let Some(hat) = value.strip_prefix('h') {
+        hat.split_once('.')
+            .is_some_and(|(hat, bit)| is_index(hat) && matches!(bit, "1" | "2" | "4" | "8"))
+    } else {
+        false
+    }
+}
+
+/// Why a mapping line is invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MappingError {
+    /// The guid must be 32 hex digits.
+    InvalidGuid(String),
+    MissingName,
+    /// The name must not be empty or contain nul bytes, and must be shorter than 128 bytes.
+    InvalidName(String),
+    /// A field is not `key:value`, or the value of a gamepad element is not a joystick input.
+    InvalidField(String),
+    UnknownPlatform(String),
+}
+impl Display for MappingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidGuid(guid) => write!(f, "invalid guid {guid:?}"),
+            Self::MissingName => f.write_str("missing controller name"),
+            Self::InvalidName(name) => write!(f, "invalid controller name {name:?}"),
+            Self::InvalidField(field) => write!(f, "invalid field {field:?}"),
+            Self::UnknownPlatform(platform) => write!(f, "unknown platform {platform:?}"),
+        }
+    }
+}
+impl std::error::Error for MappingError {}
+
+/// A [`MappingError`] along with the line number (starting at 1) it occurred on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineError {
+    pub line_number: usize,
+    pub error: MappingError,
+}
+impl Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line_number, self.error)
+    }
+}
+impl std::error::Error for LineError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Parses the mappings in `text` (one per line, like `gamecontrollerdb.txt`).
+///
+/// Empty lines and comments (starting with `#`) are skipped.
+/// Returns the valid mappings and the errors of the invalid lines.
+pub fn parse_mappings(text: &str) -> (Vec<Mapping<'_>>, Vec<LineError>) {
+    let mut mappings = vec![];
+    let mut errors = vec![];
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        match Mapping::parse(line) {
+            Ok(mapping) => mappings.push(mapping),
+            Err(error) => errors.push(LineError {
+                line_number: index + 1,
+                error,
+            }),
+        }
+    }
+    (mappings, errors)
+}
+
+/// Adds (or replaces) `mappings` using `glfwUpdateGamepadMappings`.
+///
+/// # Safety
+/// Same as `glfwUpdateGamepadMappings`.
+pub unsafe fn update_gamepad_mappings(mappings: &[Mapping<'_>]) -> Result<(), GlfwError> {
+    let mut text = String::new();
+    for mapping in mappings {
+        text.push_str(mapping.line);
+        text.push('\n');
+    }
+    // parsed mappings can't contain nul bytes, but the fields are public.
+    let Ok(text) = CString::new(text) else {
+        return Err(GlfwError {
+            code: ErrorCode::InvalidValue,
+            description: "gamepad mappings contain a nul byte".to_string(),
+        });
+    };
+    if glfwUpdateGamepadMappings(text.as_ptr()) == GLFW_TRUE {
+        Ok(())
+    } else {
+        Err(get_error().unwrap_or(GlfwError {
+            code: ErrorCode::InvalidValue,
+            description: String::new(),
+        }))
+    }
+}
+
+/// Applies the mappings for the current platform from the embedded [`GAMECONTROLLERDB`].
+///
+/// Returns the number of mappings that were applied. Lines that glfw can't use
+/// (eg: SDL's `xinput` mapping without a guid) are skipped.
+///
+/// # Safety
+/// Same as `glfwUpdateGamepadMappings`.
+#[cfg(feature = "gamecontrollerdb")]
+pub unsafe fn update_bundled_mappings() -> Result<usize, GlfwError> {
+    let (mappings, _) = parse_mappings(GAMECONTROLLERDB);
+    let mappings: Vec<_> = mappings
+        .into_iter()
+        .filter(|mapping| mapping.is_for_platform(Platform::current()))
+        .collect();
+    update_gamepad_mappings(&mappings)?;
+    Ok(mappings.len())
+}
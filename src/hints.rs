@@ -0,0 +1,497 @@
+//! Typed builders for glfw hints.
+//!
+//! [`InitHints`](crate::hints::InitHints) covers the `glfwInitHint` hints, and (with glfw 3.4)
+//! picks the first available platform out of a list of preferred ones.
+//!
+//! [`WindowHints`](crate::hints::WindowHints) covers every `glfwWindowHint`/`glfwWindowHintString`
+//! hint, and catches invalid context configurations (eg: a core profile with OpenGL 2.1)
+//! before `glfwCreateWindow` fails with them.
+//!
+//! ```no_run
+//! use glfw_sys::hints::*;
+//! use glfw_sys::*;
+//! unsafe {
+//!     assert_eq!(glfwInit(), GLFW_TRUE);
+//!     WindowHints::new()
+//!         .client_api(ClientApi::OpenGl)
+//!         .context_version(3, 3)
+//!         .opengl_profile(OpenGlProfile::Core)
+//!         .samples(Some(4))
+//!         .resizable(false)
+//!         .apply()
+//!         .expect("invalid hints");
+//!     let window = glfwCreateWindow(800, 600, c"hints".as_ptr(), std::ptr::null_mut(), std::ptr::null_mut());
+//! }
+//! ```
+#[cfg(glfw_3_4)]
+use crate::error::{get_error, ErrorCode, GlfwError};
+use crate::input::glfw_enum;
+use crate::*;
+use std::collections::BTreeMap;
+use std::ffi::{c_int, CString};
+use std::fmt::{self, Display};
+
+#[cfg(glfw_3_4)]
+glfw_enum! {
+    /// Values of `GLFW_PLATFORM`, as returned by `glfwGetPlatform`.
+    pub enum Platform {
+        Any = GLFW_ANY_PLATFORM => "ANY_PLATFORM",
+        Win32 = GLFW_PLATFORM_WIN32 => "PLATFORM_WIN32",
+        Cocoa = GLFW_PLATFORM_COCOA => "PLATFORM_COCOA",
+        Wayland = GLFW_PLATFORM_WAYLAND => "PLATFORM_WAYLAND",
+        X11 = GLFW_PLATFORM_X11 => "PLATFORM_X11",
+        Null = GLFW_PLATFORM_NULL => "PLATFORM_NULL",
+    }
+}
+#[cfg(glfw_3_4)]
+impl Platform {
+    /// Whether support for this platform was compiled into glfw, using `glfwPlatformSupported`.
+    ///
+    /// Always true for [`Platform::Any`].
+    /// This doesn't check if the platform is usable (eg: `$DISPLAY` is set for X11).
+    pub fn is_supported(self) -> bool {
+        // Safety: glfwPlatformSupported can be called before init, from any thread.
+        self == Self::Any || unsafe { glfwPlatformSupported(self.into()) } == GLFW_TRUE
+    }
+}
+#[cfg(glfw_3_4)]
+glfw_enum! {
+    /// Values of `GLFW_ANGLE_PLATFORM_TYPE`.
+    pub enum AnglePlatformType {
+        None = GLFW_ANGLE_PLATFORM_TYPE_NONE => "ANGLE_PLATFORM_TYPE_NONE",
+        OpenGl = GLFW_ANGLE_PLATFORM_TYPE_OPENGL => "ANGLE_PLATFORM_TYPE_OPENGL",
+        OpenGlEs = GLFW_ANGLE_PLATFORM_TYPE_OPENGLES => "ANGLE_PLATFORM_TYPE_OPENGLES",
+        D3d9 = GLFW_ANGLE_PLATFORM_TYPE_D3D9 => "ANGLE_PLATFORM_TYPE_D3D9",
+        D3d11 = GLFW_ANGLE_PLATFORM_TYPE_D3D11 => "ANGLE_PLATFORM_TYPE_D3D11",
+        Vulkan = GLFW_ANGLE_PLATFORM_TYPE_VULKAN => "ANGLE_PLATFORM_TYPE_VULKAN",
+        Metal = GLFW_ANGLE_PLATFORM_TYPE_METAL => "ANGLE_PLATFORM_TYPE_METAL",
+    }
+}
+glfw_enum! {
+    /// Values of `GLFW_WAYLAND_LIBDECOR`.
+    pub enum WaylandLibdecor {
+        Prefer = GLFW_WAYLAND_PREFER_LIBDECOR => "WAYLAND_PREFER_LIBDECOR",
+        Disable = GLFW_WAYLAND_DISABLE_LIBDECOR => "WAYLAND_DISABLE_LIBDECOR",
+    }
+}
+
+/// Initialization hints for `glfwInit`.
+///
+/// Hints that are not set keep their current values (init hints are not reset by glfw).
+///
+/// To force X11 under XWayland when glfw was built with both `x11` and `wayland`,
+/// use `.platforms(&[Platform::X11, Platform::Any])`: it picks X11 when it is
+/// available, and lets glfw choose otherwise.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InitHints {
+    hints: BTreeMap<c_int, c_int>,
+    #[cfg(glfw_3_4)]
+    platforms: Vec<Platform>,
+}
+impl InitHints {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Sets a raw hint, for hints that are not covered by the setters (eg: from a newer glfw).
+    pub fn hint(mut self, hint: c_int, value: c_int) -> Self {
+        self.hints.insert(hint, value);
+        self
+    }
+    /// `GLFW_PLATFORM`
+    #[cfg(glfw_3_4)]
+    pub fn platform(self, platform: Platform) -> Self {
+        self.platforms(&[platform])
+    }
+    /// Platforms to try in order of preference, see [`InitHints::init`].
+    #[cfg(glfw_3_4)]
+    pub fn platforms(mut self, platforms: &[Platform]) -> Self {
+        self.platforms = platforms.to_vec();
+        self
+    }
+    /// `GLFW_JOYSTICK_HAT_BUTTONS`
+    pub fn joystick_hat_buttons(self, value: bool) -> Self {
+        self.hint(GLFW_JOYSTICK_HAT_BUTTONS, glfw_bool(value))
+    }
+    /// `GLFW_ANGLE_PLATFORM_TYPE`
+    #[cfg(glfw_3_4)]
+    pub fn angle_platform_type(self, value: AnglePlatformType) -> Self {
+        self.hint(GLFW_ANGLE_PLATFORM_TYPE, value.into())
+    }
+    /// `GLFW_COCOA_CHDIR_RESOURCES`
+    pub fn cocoa_chdir_resources(self, value: bool) -> Self {
+        self.hint(GLFW_COCOA_CHDIR_RESOURCES, glfw_bool(value))
+    }
+    /// `GLFW_COCOA_MENUBAR`
+    pub fn cocoa_menubar(self, value: bool) -> Self {
+        self.hint(GLFW_COCOA_MENUBAR, glfw_bool(value))
+    }
+    /// `GLFW_X11_XCB_VULKAN_SURFACE`
+    #[cfg(glfw_3_4)]
+    pub fn x11_xcb_vulkan_surface(self, value: bool) -> Self {
+        self.hint(GLFW_X11_XCB_VULKAN_SURFACE, glfw_bool(value))
+    }
+    /// `GLFW_WAYLAND_LIBDECOR`
+    pub fn wayland_libdecor(self, value: WaylandLibdecor) -> Self {
+        self.hint(GLFW_WAYLAND_LIBDECOR, value.into())
+    }
+
+    /// Sets the hints (except the platform) with `glfwInitHint`.
+    ///
+    /// With glfw 3.3, call this before `glfwInit`, as `init` needs glfw 3.4.
+    ///
+    /// # Safety
+    /// Same as `glfwInitHint`.
+    pub unsafe fn apply(&self) {
+        for (&hint, &value) in &self.hints {
+            glfwInitHint(hint, value);
+        }
+    }
+
+    /// Applies the hints and initializes glfw with the first platform that works.
+    ///
+    /// Platforms that were not compiled into glfw are skipped (see [`Platform::is_supported`]),
+    /// and so are platforms for which `glfwInit` fails with `GLFW_PLATFORM_UNAVAILABLE`
+    /// (eg: X11 without `$DISPLAY`). Any other error is returned immediately.
+    /// Without any platforms, glfw picks one ([`Platform::Any`]).
+    ///
+    /// Returns the platform that was chosen, from `glfwGetPlatform`.
+    ///
+    /// # Safety
+    /// Same as `glfwInit`.
+    #[cfg(glfw_3_4)]
+    pub unsafe fn init(&self) -> Result<Platform, GlfwError> {
+        let platforms = if self.platforms.is_empty() {
+            &[Platform::Any][..]
+        } else {
+            &self.platforms[..]
+        };
+        let mut error = None;
+        for &platform in platforms {
+            if !platform.is_supported() {
+                error = Some(GlfwError {
+                    code: ErrorCode::PlatformUnavailable,
+                    description: format!("glfw was built without {platform} support"),
+                });
+                continue;
+            }
+            self.apply();
+            glfwInitHint(GLFW_PLATFORM, platform.into());
+            if glfwInit() == GLFW_TRUE {
+                return Ok(Platform::try_from(glfwGetPlatform())
+                    .expect("glfw reported an invalid platform"));
+            }
+            match get_error() {
+                Some(e) if e.code == ErrorCode::PlatformUnavailable => error = Some(e),
+                e => {
+                    return Err(e.unwrap_or(GlfwError {
+                        code: ErrorCode::PlatformError,
+                        description: String::new(),
+                    }))
+                }
+            }
+        }
+        Err(error.expect("there is at least one platform"))
+    }
+}
+
+fn glfw_bool(value: bool) -> c_int {
+    if value {
+        GLFW_TRUE
+    } else {
+        GLFW_FALSE
+    }
+}
+
+glfw_enum! {
+    /// Values of `GLFW_CLIENT_API`.
+    pub enum ClientApi {
+        NoApi = GLFW_NO_API => "NO_API",
+        OpenGl = GLFW_OPENGL_API => "OPENGL_API",
+        OpenGlEs = GLFW_OPENGL_ES_API => "OPENGL_ES_API",
+    }
+}
+glfw_enum! {
+    /// Values of `GLFW_CONTEXT_CREATION_API`.
+    pub enum ContextCreationApi {
+        Native = GLFW_NATIVE_CONTEXT_API => "NATIVE_CONTEXT_API",
+        Egl = GLFW_EGL_CONTEXT_API => "EGL_CONTEXT_API",
+        OsMesa = GLFW_OSMESA_CONTEXT_API => "OSMESA_CONTEXT_API",
+    }
+}
+glfw_enum! {
+    /// Values of `GLFW_OPENGL_PROFILE`.
+    pub enum OpenGlProfile {
+        Any = GLFW_OPENGL_ANY_PROFILE => "OPENGL_ANY_PROFILE",
+        Core = GLFW_OPENGL_CORE_PROFILE => "OPENGL_CORE_PROFILE",
+        Compat = GLFW_OPENGL_COMPAT_PROFILE => "OPENGL_COMPAT_PROFILE",
+    }
+}
+glfw_enum! {
+    /// Values of `GLFW_CONTEXT_ROBUSTNESS`.
+    pub enum ContextRobustness {
+        NoRobustness = GLFW_NO_ROBUSTNESS => "NO_ROBUSTNESS",
+        NoResetNotification = GLFW_NO_RESET_NOTIFICATION => "NO_RESET_NOTIFICATION",
+        LoseContextOnReset = GLFW_LOSE_CONTEXT_ON_RESET => "LOSE_CONTEXT_ON_RESET",
+    }
+}
+glfw_enum! {
+    /// Values of `GLFW_CONTEXT_RELEASE_BEHAVIOR`.
+    pub enum ReleaseBehavior {
+        Any = GLFW_ANY_RELEASE_BEHAVIOR => "ANY_RELEASE_BEHAVIOR",
+        Flush = GLFW_RELEASE_BEHAVIOR_FLUSH => "RELEASE_BEHAVIOR_FLUSH",
+        None = GLFW_RELEASE_BEHAVIOR_NONE => "RELEASE_BEHAVIOR_NONE",
+    }
+}
+
+/// Why a set of hints is invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HintError {
+    /// The context version doesn't exist for the client api (eg: OpenGL 3.4 or OpenGL ES 2.1).
+    InvalidContextVersion {
+        api: ClientApi,
+        major: c_int,
+        minor: c_int,
+    },
+    /// Core and compat profiles were added in OpenGL 3.2.
+    ProfileRequiresOpenGl32 { major: c_int, minor: c_int },
+    /// Forward compatible contexts were added in OpenGL 3.0.
+    ForwardCompatRequiresOpenGl30 { major: c_int, minor: c_int },
+    /// OpenGL profiles and forward compatibility don't apply to OpenGL ES.
+    OpenGlOnlyHint(&'static str),
+    /// String hints can't contain nul bytes.
+    NulInString(&'static str),
+}
+impl Display for HintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidContextVersion { api, major, minor } => {
+                write!(f, "{major}.{minor} is not a valid {api} version")
+            }
+            Self::ProfileRequiresOpenGl32 { major, minor } => write!(
+                f,
+                "OpenGL profiles require OpenGL 3.2 or above, but {major}.{minor} was requested"
+            ),
+            Self::ForwardCompatRequiresOpenGl30 { major, minor } => write!(
+                f,
+                "forward compatibility requires OpenGL 3.0 or above, but {major}.{minor} was requested"
+            ),
+            Self::OpenGlOnlyHint(hint) => write!(f, "{hint} can't be used with OpenGL ES"),
+            Self::NulInString(hint) => write!(f, "value of {hint} contains a nul byte"),
+        }
+    }
+}
+impl std::error::Error for HintError {}
+
+/// Window and context hints for `glfwCreateWindow`.
+///
+/// Hints that are not set keep their default values.
+/// Use `None` for framebuffer hints to set them to `GLFW_DONT_CARE`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WindowHints {
+    hints: BTreeMap<c_int, c_int>,
+    string_hints: BTreeMap<c_int, (&'static str, String)>,
+}
+
+/// Generates setters for hints that take a `bool`.
+macro_rules! bool_hints {
+    ($($(#[$attr:meta])* $setter:ident => $hint:ident;)*) => {
+        $(
+            $(#[$attr])*
+            #[doc = concat!("`", stringify!($hint), "`")]
+            pub fn $setter(self, value: bool) -> Self {
+                self.hint($hint, glfw_bool(value))
+            }
+        )*
+    };
+}
+/// Generates setters for hints that take a number or `GLFW_DONT_CARE` (`None`).
+macro_rules! count_hints {
+    ($($setter:ident => $hint:ident;)*) => {
+        $(
+            #[doc = concat!("`", stringify!($hint), "`. `None` is `GLFW_DONT_CARE`.")]
+            pub fn $setter(self, value: Option<u16>) -> Self {
+                self.hint($hint, value.map_or(GLFW_DONT_CARE, c_int::from))
+            }
+        )*
+    };
+}
+/// Generates setters for hints that take a string.
+macro_rules! string_hints {
+    ($($(#[$attr:meta])* $setter:ident => $hint:ident;)*) => {
+        $(
+            $(#[$attr])*
+            #[doc = concat!("`", stringify!($hint), "`")]
+            pub fn $setter(mut self, value: &str) -> Self {
+                self.string_hints
+                    .insert($hint, (stringify!($hint), value.to_string()));
+                self
+            }
+        )*
+    };
+}
+
+impl WindowHints {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Sets a raw hint, for hints that are not covered by the setters (eg: from a newer glfw).
+    pub fn hint(mut self, hint: c_int, value: c_int) -> Self {
+        self.hints.insert(hint, value);
+        self
+    }
+    /// The raw value of a hint, if it was set.
+    pub fn get(&self, hint: c_int) -> Option<c_int> {
+        self.hints.get(&hint).copied()
+    }
+
+    bool_hints! {
+        focused => GLFW_FOCUSED;
+        resizable => GLFW_RESIZABLE;
+        visible => GLFW_VISIBLE;
+        decorated => GLFW_DECORATED;
+        auto_iconify => GLFW_AUTO_ICONIFY;
+        floating => GLFW_FLOATING;
+        maximized => GLFW_MAXIMIZED;
+        center_cursor => GLFW_CENTER_CURSOR;
+        transparent_framebuffer => GLFW_TRANSPARENT_FRAMEBUFFER;
+        focus_on_show => GLFW_FOCUS_ON_SHOW;
+        #[cfg(glfw_3_4)]
+        mouse_passthrough => GLFW_MOUSE_PASSTHROUGH;
+        scale_to_monitor => GLFW_SCALE_TO_MONITOR;
+        #[cfg(glfw_3_4)]
+        scale_framebuffer => GLFW_SCALE_FRAMEBUFFER;
+        stereo => GLFW_STEREO;
+        srgb_capable => GLFW_SRGB_CAPABLE;
+        doublebuffer => GLFW_DOUBLEBUFFER;
+        opengl_forward_compat => GLFW_OPENGL_FORWARD_COMPAT;
+        /// Renamed to `GLFW_CONTEXT_DEBUG` in glfw 3.4.
+        context_debug => GLFW_OPENGL_DEBUG_CONTEXT;
+        context_no_error => GLFW_CONTEXT_NO_ERROR;
+        /// Older name of `scale_framebuffer`.
+        cocoa_retina_framebuffer => GLFW_COCOA_RETINA_FRAMEBUFFER;
+        cocoa_graphics_switching => GLFW_COCOA_GRAPHICS_SWITCHING;
+        #[cfg(glfw_3_4)]
+        win32_keyboard_menu => GLFW_WIN32_KEYBOARD_MENU;
+        #[cfg(glfw_3_4)]
+        win32_showdefault => GLFW_WIN32_SHOWDEFAULT;
+    }
+    count_hints! {
+        red_bits => GLFW_RED_BITS;
+        green_bits => GLFW_GREEN_BITS;
+        blue_bits => GLFW_BLUE_BITS;
+        alpha_bits => GLFW_ALPHA_BITS;
+        depth_bits => GLFW_DEPTH_BITS;
+        stencil_bits => GLFW_STENCIL_BITS;
+        accum_red_bits => GLFW_ACCUM_RED_BITS;
+        accum_green_bits => GLFW_ACCUM_GREEN_BITS;
+        accum_blue_bits => GLFW_ACCUM_BLUE_BITS;
+        accum_alpha_bits => GLFW_ACCUM_ALPHA_BITS;
+        aux_buffers => GLFW_AUX_BUFFERS;
+        samples => GLFW_SAMPLES;
+        refresh_rate => GLFW_REFRESH_RATE;
+    }
+    string_hints! {
+        cocoa_frame_name => GLFW_COCOA_FRAME_NAME;
+        x11_class_name => GLFW_X11_CLASS_NAME;
+        x11_instance_name => GLFW_X11_INSTANCE_NAME;
+        #[cfg(glfw_3_4)]
+        wayland_app_id => GLFW_WAYLAND_APP_ID;
+    }
+
+    /// `GLFW_POSITION_X` and `GLFW_POSITION_Y`
+    #[cfg(glfw_3_4)]
+    pub fn position(self, x: c_int, y: c_int) -> Self {
+        self.hint(GLFW_POSITION_X, x).hint(GLFW_POSITION_Y, y)
+    }
+    /// `GLFW_CLIENT_API`
+    pub fn client_api(self, api: ClientApi) -> Self {
+        self.hint(GLFW_CLIENT_API, api.into())
+    }
+    /// `GLFW_CONTEXT_CREATION_API`
+    pub fn context_creation_api(self, api: ContextCreationApi) -> Self {
+        self.hint(GLFW_CONTEXT_CREATION_API, api.into())
+    }
+    /// `GLFW_CONTEXT_VERSION_MAJOR` and `GLFW_CONTEXT_VERSION_MINOR`
+    pub fn context_version(self, major: c_int, minor: c_int) -> Self {
+        self.hint(GLFW_CONTEXT_VERSION_MAJOR, major)
+            .hint(GLFW_CONTEXT_VERSION_MINOR, minor)
+    }
+    /// `GLFW_OPENGL_PROFILE`
+    pub fn opengl_profile(self, profile: OpenGlProfile) -> Self {
+        self.hint(GLFW_OPENGL_PROFILE, profile.into())
+    }
+    /// `GLFW_CONTEXT_ROBUSTNESS`
+    pub fn context_robustness(self, robustness: ContextRobustness) -> Self {
+        self.hint(GLFW_CONTEXT_ROBUSTNESS, robustness.into())
+    }
+    /// `GLFW_CONTEXT_RELEASE_BEHAVIOR`
+    pub fn context_release_behavior(self, behavior: ReleaseBehavior) -> Self {
+        self.hint(GLFW_CONTEXT_RELEASE_BEHAVIOR, behavior.into())
+    }
+
+    /// Checks the context hints the same way `glfwCreateWindow` does.
+    pub fn validate(&self) -> Result<(), HintError> {
+        for (name, value) in self.string_hints.values() {
+            if value.contains('\0') {
+                return Err(HintError::NulInString(name));
+            }
+        }
+        let api = match self.get(GLFW_CLIENT_API) {
+            None => ClientApi::OpenGl,
+            Some(api) => match ClientApi::try_from(api) {
+                Ok(ClientApi::NoApi) | Err(_) => return Ok(()),
+                Ok(api) => api,
+            },
+        };
+        let major = self.get(GLFW_CONTEXT_VERSION_MAJOR).unwrap_or(1);
+        let minor = self.get(GLFW_CONTEXT_VERSION_MINOR).unwrap_or(0);
+        let profile = self
+            .get(GLFW_OPENGL_PROFILE)
+            .unwrap_or(GLFW_OPENGL_ANY_PROFILE);
+        let forward_compat = self.get(GLFW_OPENGL_FORWARD_COMPAT) == Some(GLFW_TRUE);
+        let valid_version = match api {
+            ClientApi::OpenGl => matches!(
+                (major, minor),
+                (1, 0..=5) | (2, 0..=1) | (3, 0..=3) | (4.., 0..)
+            ),
+            _ => matches!((major, minor), (1, 0..=1) | (2, 0) | (3.., 0..)),
+        };
+        if !valid_version {
+            return Err(HintError::InvalidContextVersion { api, major, minor });
+        }
+        if api == ClientApi::OpenGlEs {
+            if profile != GLFW_OPENGL_ANY_PROFILE {
+                return Err(HintError::OpenGlOnlyHint("GLFW_OPENGL_PROFILE"));
+            }
+            if forward_compat {
+                return Err(HintError::OpenGlOnlyHint("GLFW_OPENGL_FORWARD_COMPAT"));
+            }
+            return Ok(());
+        }
+        if profile != GLFW_OPENGL_ANY_PROFILE && (major, minor) < (3, 2) {
+            return Err(HintError::ProfileRequiresOpenGl32 { major, minor });
+        }
+        if forward_compat && major < 3 {
+            return Err(HintError::ForwardCompatRequiresOpenGl30 { major, minor });
+        }
+        Ok(())
+    }
+
+    /// Validates the hints, then resets all hints with `glfwDefaultWindowHints`
+    /// and sets the hints of this builder.
+    ///
+    /// # Safety
+    /// Same as `glfwWindowHint`.
+    pub unsafe fn apply(&self) -> Result<(), HintError> {
+        self.validate()?;
+        glfwDefaultWindowHints();
+        for (&hint, &value) in &self.hints {
+            glfwWindowHint(hint, value);
+        }
+        for (&hint, (_, value)) in &self.string_hints {
+            let value = CString::new(value.as_str()).expect("validated above");
+            glfwWindowHintString(hint, value.as_ptr());
+        }
+        Ok(())
+    }
+}
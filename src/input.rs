@@ -0,0 +1,433 @@
+//! Typed wrappers for the key, mouse button, modifier and action constants.
+//!
+//! glfw passes all of these as plain `c_int`s (eg: `GLFW_KEY_A` in `GLFWkeyfun`).
+//! The types here convert losslessly to and from those ints with [`TryFrom`] / [`From`],
+//! and to and from their names (eg: `"LEFT_SHIFT"`) with [`Display`](std::fmt::Display) /
+//! [`FromStr`](std::str::FromStr). The names are the suffixes of the glfw constants,
+//! which makes them suitable for config files.
+//!
+//! ```
+//! use glfw_sys::input::{Key, Modifiers};
+//! let key = Key::try_from(glfw_sys::GLFW_KEY_LEFT_SHIFT).unwrap();
+//! assert_eq!(key, Key::LeftShift);
+//! assert_eq!(key.to_string(), "LEFT_SHIFT");
+//! assert_eq!("left_shift".parse::<Key>().unwrap(), key);
+//! let mods = Modifiers::SHIFT | Modifiers::CONTROL;
+//! assert_eq!(std::ffi::c_int::from(mods), glfw_sys::GLFW_MOD_SHIFT | glfw_sys::GLFW_MOD_CONTROL);
+//! assert_eq!(mods.to_string(), "SHIFT | CONTROL");
+//! ```
+use crate::*;
+use std::ffi::c_int;
+use std::fmt::{self, Display};
+use std::ops::{BitAnd, BitOr, BitOrAssign, Sub};
+use std::str::FromStr;
+
+/// Error returned when converting an int that doesn't match any glfw constant of that type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownValue {
+    /// name of the type we tried to convert to.
+    pub type_name: &'static str,
+    pub value: c_int,
+}
+impl Display for UnknownValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} is not a valid {}", self.value, self.type_name)
+    }
+}
+impl std::error::Error for UnknownValue {}
+
+/// Error returned when parsing a name that doesn't match any glfw constant of that type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownName {
+    /// name of the type we tried to parse.
+    pub type_name: &'static str,
+    pub name: String,
+}
+impl Display for UnknownName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is not a valid {} name", self.name, self.type_name)
+    }
+}
+impl std::error::Error for UnknownName {}
+
+/// Generates a `#[repr(i32)]` enum from glfw constants, along with the conversions.
+///
+/// Every variant is `Variant = GLFW_CONSTANT => "NAME"`, optionally with doc comments and a
+/// `#[cfg(..)]` (for constants that only exist in some glfw versions).
+/// Also used by the `error` and `hints` modules.
+macro_rules! glfw_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[doc = $variant_doc:literal])*
+                $(#[cfg($variant_cfg:meta)])?
+                $variant:ident = $constant:ident => $variant_name:literal,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(i32)]
+        pub enum $name {
+            $(
+                $(#[doc = $variant_doc])*
+                #[doc = concat!("`", stringify!($constant), "`")]
+                $(#[cfg($variant_cfg)])?
+                $variant = $constant,
+            )*
+        }
+        impl $name {
+            /// All the variants, in the order of their values.
+            pub const ALL: &'static [Self] = &[$($(#[cfg($variant_cfg)])? Self::$variant,)*];
+            /// Name of the variant, which is the suffix of the glfw constant.
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $($(#[cfg($variant_cfg)])? Self::$variant => $variant_name,)*
+                }
+            }
+        }
+        impl TryFrom<::std::ffi::c_int> for $name {
+            type Error = $crate::input::UnknownValue;
+            fn try_from(value: ::std::ffi::c_int) -> Result<Self, Self::Error> {
+                #[allow(non_upper_case_globals, reason = "glfw constants")]
+                match value {
+                    $($(#[cfg($variant_cfg)])? $constant => Ok(Self::$variant),)*
+                    value => Err($crate::input::UnknownValue {
+                        type_name: stringify!($name),
+                        value,
+                    }),
+                }
+            }
+        }
+        impl From<$name> for ::std::ffi::c_int {
+            fn from(value: $name) -> Self {
+                value as ::std::ffi::c_int
+            }
+        }
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.name())
+            }
+        }
+        /// Parses the name of a variant (case insensitive).
+        impl ::std::str::FromStr for $name {
+            type Err = $crate::input::UnknownName;
+            fn from_str(name: &str) -> Result<Self, Self::Err> {
+                Self::ALL
+                    .iter()
+                    .copied()
+                    .find(|variant| variant.name().eq_ignore_ascii_case(name))
+                    .ok_or_else(|| $crate::input::UnknownName {
+                        type_name: stringify!($name),
+                        name: name.to_string(),
+                    })
+            }
+        }
+    };
+}
+pub(crate) use glfw_enum;
+
+glfw_enum! {
+    /// Keyboard keys, as used by `GLFWkeyfun` and `glfwGetKey`.
+    ///
+    /// These are named after the US keyboard layout.
+    /// Use `glfwGetKeyName` for the layout specific name that you show to users.
+    pub enum Key {
+        Unknown = GLFW_KEY_UNKNOWN => "UNKNOWN",
+        Space = GLFW_KEY_SPACE => "SPACE",
+        Apostrophe = GLFW_KEY_APOSTROPHE => "APOSTROPHE",
+        Comma = GLFW_KEY_COMMA => "COMMA",
+        Minus = GLFW_KEY_MINUS => "MINUS",
+        Period = GLFW_KEY_PERIOD => "PERIOD",
+        Slash = GLFW_KEY_SLASH => "SLASH",
+        Num0 = GLFW_KEY_0 => "0",
+        Num1 = GLFW_KEY_1 => "1",
+        Num2 = GLFW_KEY_2 => "2",
+        Num3 = GLFW_KEY_3 => "3",
+        Num4 = GLFW_KEY_4 => "4",
+        Num5 = GLFW_KEY_5 => "5",
+        Num6 = GLFW_KEY_6 => "6",
+        Num7 = GLFW_KEY_7 => "7",
+        Num8 = GLFW_KEY_8 => "8",
+        Num9 = GLFW_KEY_9 => "9",
+        Semicolon = GLFW_KEY_SEMICOLON => "SEMICOLON",
+        Equal = GLFW_KEY_EQUAL => "EQUAL",
+        A = GLFW_KEY_A => "A",
+        B = GLFW_KEY_B => "B",
+        C = GLFW_KEY_C => "C",
+        D = GLFW_KEY_D => "D",
+        E = GLFW_KEY_E => "E",
+        F = GLFW_KEY_F => "F",
+        G = GLFW_KEY_G => "G",
+        H = GLFW_KEY_H => "H",
+        I = GLFW_KEY_I => "I",
+        J = GLFW_KEY_J => "J",
+        K = GLFW_KEY_K => "K",
+        L = GLFW_KEY_L => "L",
+        M = GLFW_KEY_M => "M",
+        N = GLFW_KEY_N => "N",
+        O = GLFW_KEY_O => "O",
+        P = GLFW_KEY_P => "P",
+        Q = GLFW_KEY_Q => "Q",
+        R = GLFW_KEY_R => "R",
+        S = GLFW_KEY_S => "S",
+        T = GLFW_KEY_T => "T",
+        U = GLFW_KEY_U => "U",
+        V = GLFW_KEY_V => "V",
+        W = GLFW_KEY_W => "W",
+        X = GLFW_KEY_X => "X",
+        Y = GLFW_KEY_Y => "Y",
+        Z = GLFW_KEY_Z => "Z",
+        LeftBracket = GLFW_KEY_LEFT_BRACKET => "LEFT_BRACKET",
+        Backslash = GLFW_KEY_BACKSLASH => "BACKSLASH",
+        RightBracket = GLFW_KEY_RIGHT_BRACKET => "RIGHT_BRACKET",
+        GraveAccent = GLFW_KEY_GRAVE_ACCENT => "GRAVE_ACCENT",
+        World1 = GLFW_KEY_WORLD_1 => "WORLD_1",
+        World2 = GLFW_KEY_WORLD_2 => "WORLD_2",
+        Escape = GLFW_KEY_ESCAPE => "ESCAPE",
+        Enter = GLFW_KEY_ENTER => "ENTER",
+        Tab = GLFW_KEY_TAB => "TAB",
+        Backspace = GLFW_KEY_BACKSPACE => "BACKSPACE",
+        Insert = GLFW_KEY_INSERT => "INSERT",
+        Delete = GLFW_KEY_DELETE => "DELETE",
+        Right = GLFW_KEY_RIGHT => "RIGHT",
+        Left = GLFW_KEY_LEFT => "LEFT",
+        Down = GLFW_KEY_DOWN => "DOWN",
+        Up = GLFW_KEY_UP => "UP",
+        PageUp = GLFW_KEY_PAGE_UP => "PAGE_UP",
+        PageDown = GLFW_KEY_PAGE_DOWN => "PAGE_DOWN",
+        Home = GLFW_KEY_HOME => "HOME",
+        End = GLFW_KEY_END => "END",
+        CapsLock = GLFW_KEY_CAPS_LOCK => "CAPS_LOCK",
+        ScrollLock = GLFW_KEY_SCROLL_LOCK => "SCROLL_LOCK",
+        NumLock = GLFW_KEY_NUM_LOCK => "NUM_LOCK",
+        PrintScreen = GLFW_KEY_PRINT_SCREEN => "PRINT_SCREEN",
+        Pause = GLFW_KEY_PAUSE => "PAUSE",
+        F1 = GLFW_KEY_F1 => "F1",
+        F2 = GLFW_KEY_F2 => "F2",
+        F3 = GLFW_KEY_F3 => "F3",
+        F4 = GLFW_KEY_F4 => "F4",
+        F5 = GLFW_KEY_F5 => "F5",
+        F6 = GLFW_KEY_F6 => "F6",
+        F7 = GLFW_KEY_F7 => "F7",
+        F8 = GLFW_KEY_F8 => "F8",
+        F9 = GLFW_KEY_F9 => "F9",
+        F10 = GLFW_KEY_F10 => "F10",
+        F11 = GLFW_KEY_F11 => "F11",
+        F12 = GLFW_KEY_F12 => "F12",
+        F13 = GLFW_KEY_F13 => "F13",
+        F14 = GLFW_KEY_F14 => "F14",
+        F15 = GLFW_KEY_F15 => "F15",
+        F16 = GLFW_KEY_F16 => "F16",
+        F17 = GLFW_KEY_F17 => "F17",
+        F18 = GLFW_KEY_F18 => "F18",
+        F19 = GLFW_KEY_F19 => "F19",
+        F20 = GLFW_KEY_F20 => "F20",
+        F21 = GLFW_KEY_F21 => "F21",
+        F22 = GLFW_KEY_F22 => "F22",
+        F23 = GLFW_KEY_F23 => "F23",
+        F24 = GLFW_KEY_F24 => "F24",
+        F25 = GLFW_KEY_F25 => "F25",
+        Kp0 = GLFW_KEY_KP_0 => "KP_0",
+        Kp1 = GLFW_KEY_KP_1 => "KP_1",
+        Kp2 = GLFW_KEY_KP_2 => "KP_2",
+        Kp3 = GLFW_KEY_KP_3 => "KP_3",
+        Kp4 = GLFW_KEY_KP_4 => "KP_4",
+        Kp5 = GLFW_KEY_KP_5 => "KP_5",
+        Kp6 = GLFW_KEY_KP_6 => "KP_6",
+        Kp7 = GLFW_KEY_KP_7 => "KP_7",
+        Kp8 = GLFW_KEY_KP_8 => "KP_8",
+        Kp9 = GLFW_KEY_KP_9 => "KP_9",
+        KpDecimal = GLFW_KEY_KP_DECIMAL => "KP_DECIMAL",
+        KpDivide = GLFW_KEY_KP_DIVIDE => "KP_DIVIDE",
+        KpMultiply = GLFW_KEY_KP_MULTIPLY => "KP_MULTIPLY",
+        KpSubtract = GLFW_KEY_KP_SUBTRACT => "KP_SUBTRACT",
+        KpAdd = GLFW_KEY_KP_ADD => "KP_ADD",
+        KpEnter = GLFW_KEY_KP_ENTER => "KP_ENTER",
+        KpEqual = GLFW_KEY_KP_EQUAL => "KP_EQUAL",
+        LeftShift = GLFW_KEY_LEFT_SHIFT => "LEFT_SHIFT",
+        LeftControl = GLFW_KEY_LEFT_CONTROL => "LEFT_CONTROL",
+        LeftAlt = GLFW_KEY_LEFT_ALT => "LEFT_ALT",
+        LeftSuper = GLFW_KEY_LEFT_SUPER => "LEFT_SUPER",
+        RightShift = GLFW_KEY_RIGHT_SHIFT => "RIGHT_SHIFT",
+        RightControl = GLFW_KEY_RIGHT_CONTROL => "RIGHT_CONTROL",
+        RightAlt = GLFW_KEY_RIGHT_ALT => "RIGHT_ALT",
+        RightSuper = GLFW_KEY_RIGHT_SUPER => "RIGHT_SUPER",
+        Menu = GLFW_KEY_MENU => "MENU",
+    }
+}
+
+glfw_enum! {
+    /// Mouse buttons, as used by `GLFWmousebuttonfun` and `glfwGetMouseButton`.
+    pub enum MouseButton {
+        Left = GLFW_MOUSE_BUTTON_LEFT => "LEFT",
+        Right = GLFW_MOUSE_BUTTON_RIGHT => "RIGHT",
+        Middle = GLFW_MOUSE_BUTTON_MIDDLE => "MIDDLE",
+        Button4 = GLFW_MOUSE_BUTTON_4 => "4",
+        Button5 = GLFW_MOUSE_BUTTON_5 => "5",
+        Button6 = GLFW_MOUSE_BUTTON_6 => "6",
+        Button7 = GLFW_MOUSE_BUTTON_7 => "7",
+        Button8 = GLFW_MOUSE_BUTTON_8 => "8",
+    }
+}
+
+glfw_enum! {
+    /// The action of key and mouse button events.
+    pub enum Action {
+        Release = GLFW_RELEASE => "RELEASE",
+        Press = GLFW_PRESS => "PRESS",
+        /// Only used for keys.
+        Repeat = GLFW_REPEAT => "REPEAT",
+    }
+}
+
+/// Modifier key flags (`GLFW_MOD_*`), as used by key, char-mods and mouse button callbacks.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Modifiers(c_int);
+impl Modifiers {
+    pub const SHIFT: Self = Self(GLFW_MOD_SHIFT);
+    pub const CONTROL: Self = Self(GLFW_MOD_CONTROL);
+    pub const ALT: Self = Self(GLFW_MOD_ALT);
+    pub const SUPER: Self = Self(GLFW_MOD_SUPER);
+    /// Only reported if `GLFW_LOCK_KEY_MODS` input mode is enabled.
+    pub const CAPS_LOCK: Self = Self(GLFW_MOD_CAPS_LOCK);
+    /// Only reported if `GLFW_LOCK_KEY_MODS` input mode is enabled.
+    pub const NUM_LOCK: Self = Self(GLFW_MOD_NUM_LOCK);
+    /// Every flag along with its name.
+    pub const FLAGS: &'static [(&'static str, Self)] = &[
+        ("SHIFT", Self::SHIFT),
+        ("CONTROL", Self::CONTROL),
+        ("ALT", Self::ALT),
+        ("SUPER", Self::SUPER),
+        ("CAPS_LOCK", Self::CAPS_LOCK),
+        ("NUM_LOCK", Self::NUM_LOCK),
+    ];
+
+    /// No modifiers.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+    /// All modifiers.
+    pub const fn all() -> Self {
+        Self(
+            GLFW_MOD_SHIFT
+                | GLFW_MOD_CONTROL
+                | GLFW_MOD_ALT
+                | GLFW_MOD_SUPER
+                | GLFW_MOD_CAPS_LOCK
+                | GLFW_MOD_NUM_LOCK,
+        )
+    }
+    /// The raw `GLFW_MOD_*` bits.
+    pub const fn bits(self) -> c_int {
+        self.0
+    }
+    /// Returns `None` if `bits` contains bits that are not glfw modifiers.
+    pub const fn from_bits(bits: c_int) -> Option<Self> {
+        if bits & !Self::all().0 == 0 {
+            Some(Self(bits))
+        } else {
+            None
+        }
+    }
+    /// Drops any bits that are not glfw modifiers.
+    pub const fn from_bits_truncate(bits: c_int) -> Self {
+        Self(bits & Self::all().0)
+    }
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+    /// Whether all flags in `other` are set in `self`.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+    pub fn insert(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+    pub fn remove(&mut self, other: Self) {
+        self.0 &= !other.0;
+    }
+    /// Iterates over the names and values of the flags that are set.
+    pub fn iter(self) -> impl Iterator<Item = (&'static str, Self)> {
+        Self::FLAGS
+            .iter()
+            .copied()
+            .filter(move |(_, flag)| self.contains(*flag))
+    }
+}
+impl BitOr for Modifiers {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+impl BitOrAssign for Modifiers {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+impl BitAnd for Modifiers {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+impl Sub for Modifiers {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 & !rhs.0)
+    }
+}
+impl TryFrom<c_int> for Modifiers {
+    type Error = UnknownValue;
+    fn try_from(value: c_int) -> Result<Self, Self::Error> {
+        Self::from_bits(value).ok_or(UnknownValue {
+            type_name: "Modifiers",
+            value,
+        })
+    }
+}
+impl From<Modifiers> for c_int {
+    fn from(value: Modifiers) -> Self {
+        value.0
+    }
+}
+/// Flag names separated by `" | "` (eg: `SHIFT | CONTROL`). Empty string if no flags are set.
+impl Display for Modifiers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (name, _)) in self.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" | ")?;
+            }
+            f.write_str(name)?;
+        }
+        Ok(())
+    }
+}
+impl fmt::Debug for Modifiers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Modifiers({self})")
+    }
+}
+/// Parses flag names separated by `|` (case insensitive). An empty (or blank) string is no flags.
+impl FromStr for Modifiers {
+    type Err = UnknownName;
+    fn from_str(names: &str) -> Result<Self, Self::Err> {
+        let mut mods = Self::empty();
+        if names.trim().is_empty() {
+            return Ok(mods);
+        }
+        for name in names.split('|').map(str::trim) {
+            let (_, flag) = Self::FLAGS
+                .iter()
+                .find(|(flag_name, _)| flag_name.eq_ignore_ascii_case(name))
+                .ok_or_else(|| UnknownName {
+                    type_name: "Modifiers",
+                    name: name.to_string(),
+                })?;
+            mods |= *flag;
+        }
+        Ok(mods)
+    }
+}
@@ -1,6 +1,39 @@
 #![doc = include_str!("../README.md")]
 
 pub use sys::*;
+
+/// Routing glfw's allocations to Rust's global allocator.
+#[cfg(glfw_3_4)]
+pub mod allocator;
+/// Panic-safe Rust closures as glfw callbacks.
+pub mod callbacks;
+/// Load glfw at runtime, instead of linking it at build time.
+#[cfg(feature = "dlopen")]
+pub mod dynamic;
+/// Structured error retrieval with `glfwGetError`.
+pub mod error;
+/// Window events collected into a queue, instead of callbacks.
+pub mod events;
+/// Validating and applying SDL gamepad mappings.
+pub mod gamepad;
+/// Typed builders for window and context hints.
+pub mod hints;
+/// Typed enums for keys, mouse buttons, actions and modifiers.
+pub mod input;
+/// Offscreen rendering and buffer readback with OSMesa contexts.
+#[cfg(feature = "osmesa")]
+pub mod osmesa;
+/// Checking the runtime glfw version against the bindings.
+pub mod version;
+/// ash types for glfw's vulkan functions.
+#[cfg(feature = "ash")]
+pub mod vulkan;
+/// raw-window-handle support for glfw windows.
+#[cfg(feature = "raw-window-handle")]
+pub mod window_handle;
+// window_handle needs `glfwGetPlatform` and `glfwGetCocoaView`.
+#[cfg(all(feature = "raw-window-handle", not(glfw_3_4)))]
+compile_error!("raw-window-handle feature requires glfw 3.4, but pkg-config found glfw 3.3");
 /// We use the sys module to keep the pre-generated and build-time-generated bindings
 /// separated from each other.
 #[cfg(not(feature = "bindgen"))]
@@ -9,6 +42,11 @@ mod sys {
     /// for native handles stuff.
     mod manual;
     /// if `bindgen` is not enabled, we use pre-generated bindings.
+    #[cfg(glfw_3_4)]
+    mod pregenerated;
+    /// pre-generated bindings for glfw 3.3, used by `glfw-3-3` feature when pkg-config finds 3.3.
+    #[cfg(not(glfw_3_4))]
+    #[path = "pregenerated_3_3.rs"]
     mod pregenerated;
 
     #[allow(
@@ -0,0 +1,287 @@
+//! Offscreen rendering with OSMesa, for golden-image tests on machines without a gpu.
+//!
+//! [`create_window`](crate::osmesa::create_window) creates a hidden window whose context is
+//! created by Mesa's software rasterizer (`GLFW_OSMESA_CONTEXT_API`). After rendering,
+//! [`read_color_buffer`](crate::osmesa::read_color_buffer) copies the colour buffer into an
+//! [`RgbaImage`](crate::osmesa::RgbaImage), which can be compared with a golden image or
+//! written as a PNG.
+//!
+//! glfw loads `libOSMesa` at runtime, so it must be installed (eg: `libosmesa6` on debian).
+//!
+//! ```no_run
+//! use glfw_sys::hints::WindowHints;
+//! use glfw_sys::osmesa::*;
+//! use glfw_sys::*;
+//! unsafe {
+//!     glfwInitHint(GLFW_PLATFORM, GLFW_PLATFORM_NULL);
+//!     assert_eq!(glfwInit(), GLFW_TRUE);
+//!     let window = create_window(64, 64, &WindowHints::new()).unwrap();
+//!     glfwMakeContextCurrent(window);
+//!     // render with any gl loader, using glfwGetProcAddress.
+//!     let image = read_color_buffer(window).unwrap();
+//!     image.write_png("screenshot.png").unwrap();
+//!     glfwDestroyWindow(window);
+//!     glfwTerminate();
+//! }
+//! ```
+use crate::error::{get_error, ErrorCode, GlfwError};
+use crate::hints::{ContextCreationApi, WindowHints};
+use crate::*;
+use std::ffi::{c_int, c_void};
+use std::io::Write;
+use std::path::Path;
+
+/// OSMesa pixel formats (from `osmesa.h`), as returned by `glfwGetOSMesaColorBuffer`.
+const OSMESA_BGRA: c_int = 0x1;
+const OSMESA_ARGB: c_int = 0x2;
+const OSMESA_BGR: c_int = 0x4;
+const OSMESA_RGB: c_int = 0x1907;
+const OSMESA_RGBA: c_int = 0x1908;
+
+/// An 8-bit RGBA image, with the top row first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RgbaImage {
+    pub width: u32,
+    pub height: u32,
+    /// `width * height * 4` bytes.
+    pub pixels: Vec<u8>,
+}
+impl RgbaImage {
+    /// The RGBA value of the pixel at `x`, `y` (from the top left corner).
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        assert!(x < self.width && y < self.height, "pixel out of bounds");
+        let start = (y as usize * self.width as usize + x as usize) * 4;
+        self.pixels[start..start + 4]
+            .try_into()
+            .expect("4 bytes per pixel")
+    }
+    /// Encodes the image as an uncompressed PNG.
+    ///
+    /// The files are bigger than with a real encoder, but this avoids a dependency
+    /// for small test images.
+    pub fn to_png(&self) -> Vec<u8> {
+        // every row starts with filter type 0 (none).
+        let row_len = self.width as usize * 4;
+        let mut raw = Vec::with_capacity((row_len + 1) * self.height as usize);
+        for row in self.pixels.chunks_exact(row_len.max(1)) {
+            raw.push(0);
+            raw.extend_from_slice(row);
+        }
+        let mut ihdr = Vec::with_capacity(13);
+        ihdr.extend_from_slice(&self.width.to_be_bytes());
+        ihdr.extend_from_slice(&self.height.to_be_bytes());
+        // 8 bits per channel, RGBA, default compression/filter, no interlace.
+        ihdr.extend_from_slice(&[8, 6, 0, 0, 0]);
+
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut png, b"IHDR", &ihdr);
+        png_chunk(&mut png, b"IDAT", &zlib_stored(&raw));
+        png_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+    /// Writes the image to `path` as a PNG (see [`RgbaImage::to_png`]).
+    pub fn write_png(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        std::fs::File::create(path)?.write_all(&self.to_png())
+    }
+}
+
+/// The raw depth buffer of a window, with the top row first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DepthBuffer {
+    pub width: u32,
+    pub height: u32,
+    /// 2 for 16-bit depth buffers, 4 otherwise.
+    pub bytes_per_value: u32,
+    /// `width * height` values, as stored by OSMesa (not normalized).
+    pub values: Vec<u32>,
+}
+
+/// Creates a hidden window with an OSMesa context, using `hints` for everything else.
+///
+/// # Safety
+/// Same as `glfwCreateWindow`.
+pub unsafe fn create_window(
+    width: c_int,
+    height: c_int,
+    hints: &WindowHints,
+) -> Result<*mut GLFWwindow, GlfwError> {
+    hints
+        .clone()
+        .context_creation_api(ContextCreationApi::OsMesa)
+        .visible(false)
+        .apply()
+        .map_err(|error| GlfwError {
+            code: ErrorCode::InvalidValue,
+            description: error.to_string(),
+        })?;
+    let window = glfwCreateWindow(
+        width,
+        height,
+        c"osmesa".as_ptr(),
+        std::ptr::null_mut(),
+        std::ptr::null_mut(),
+    );
+    if window.is_null() {
+        Err(last_error())
+    } else {
+        Ok(window)
+    }
+}
+
+/// Finishes rendering of `window` and copies its colour buffer.
+///
+/// # Safety
+/// `window` must be a window created with an OSMesa context.
+pub unsafe fn read_color_buffer(window: *mut GLFWwindow) -> Result<RgbaImage, GlfwError> {
+    finish(window);
+    let (mut width, mut height, mut format) = (0, 0, 0);
+    let mut buffer: *mut c_void = std::ptr::null_mut();
+    if glfwGetOSMesaColorBuffer(window, &mut width, &mut height, &mut format, &mut buffer)
+        != GLFW_TRUE
+    {
+        return Err(last_error());
+    }
+    // rgba offsets in a pixel of the format.
+    let (channels, offsets): (usize, [Option<usize>; 4]) = match format {
+        OSMESA_RGBA => (4, [Some(0), Some(1), Some(2), Some(3)]),
+        OSMESA_BGRA => (4, [Some(2), Some(1), Some(0), Some(3)]),
+        OSMESA_ARGB => (4, [Some(1), Some(2), Some(3), Some(0)]),
+        OSMESA_RGB => (3, [Some(0), Some(1), Some(2), None]),
+        OSMESA_BGR => (3, [Some(2), Some(1), Some(0), None]),
+        format => {
+            return Err(GlfwError {
+                code: ErrorCode::FormatUnavailable,
+                description: format!("unsupported OSMesa color format {format:#x}"),
+            })
+        }
+    };
+    let (width, height) = (width.max(0) as usize, height.max(0) as usize);
+    let source = std::slice::from_raw_parts(buffer as *const u8, width * height * channels);
+    let mut pixels = Vec::with_capacity(width * height * 4);
+    // OSMesa stores the bottom row first.
+    for row in source.chunks_exact((width * channels).max(1)).rev() {
+        for pixel in row.chunks_exact(channels) {
+            pixels.extend(offsets.map(|offset| offset.map_or(u8::MAX, |offset| pixel[offset])));
+        }
+    }
+    Ok(RgbaImage {
+        width: width as u32,
+        height: height as u32,
+        pixels,
+    })
+}
+
+/// Finishes rendering of `window` and copies its depth buffer.
+///
+/// # Safety
+/// `window` must be a window created with an OSMesa context.
+pub unsafe fn read_depth_buffer(window: *mut GLFWwindow) -> Result<DepthBuffer, GlfwError> {
+    finish(window);
+    let (mut width, mut height, mut bytes_per_value) = (0, 0, 0);
+    let mut buffer: *mut c_void = std::ptr::null_mut();
+    if glfwGetOSMesaDepthBuffer(
+        window,
+        &mut width,
+        &mut height,
+        &mut bytes_per_value,
+        &mut buffer,
+    ) != GLFW_TRUE
+    {
+        return Err(last_error());
+    }
+    let (width, height) = (width.max(0) as usize, height.max(0) as usize);
+    let len = width * height;
+    let mut values: Vec<u32> = match bytes_per_value {
+        2 => std::slice::from_raw_parts(buffer as *const u16, len)
+            .iter()
+            .map(|&value| value.into())
+            .collect(),
+        4 => std::slice::from_raw_parts(buffer as *const u32, len).to_vec(),
+        bytes => {
+            return Err(GlfwError {
+                code: ErrorCode::FormatUnavailable,
+                description: format!("unsupported OSMesa depth size {bytes}"),
+            })
+        }
+    };
+    // OSMesa stores the bottom row first.
+    if width > 0 {
+        values = values
+            .chunks_exact(width)
+            .rev()
+            .flatten()
+            .copied()
+            .collect();
+    }
+    Ok(DepthBuffer {
+        width: width as u32,
+        height: height as u32,
+        bytes_per_value: bytes_per_value as u32,
+        values,
+    })
+}
+
+/// Calls `glFinish` with the context of `window`, so that the buffers are up to date.
+unsafe fn finish(window: *mut GLFWwindow) {
+    let previous = glfwGetCurrentContext();
+    glfwMakeContextCurrent(window);
+    if let Some(gl_finish) = glfwGetProcAddress(c"glFinish".as_ptr()) {
+        gl_finish();
+    }
+    glfwMakeContextCurrent(previous);
+}
+
+fn last_error() -> GlfwError {
+    get_error().unwrap_or(GlfwError {
+        code: ErrorCode::PlatformError,
+        description: String::new(),
+    })
+}
+
+fn png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// Wraps `data` in a zlib stream of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut zlib = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        zlib.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        zlib.push(last as u8);
+        zlib.extend_from_slice(&len.to_le_bytes());
+        zlib.extend_from_slice(&(!len).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    zlib.extend_from_slice(&((b << 16) | a).to_be_bytes());
+    zlib
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = u32::MAX;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
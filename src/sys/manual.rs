@@ -151,6 +151,7 @@ extern "C" {
      *
      *  @ingroup native
      */
+    #[cfg(glfw_3_4)]
     pub fn glfwGetCocoaView(window: *mut GLFWwindow) -> NSView;
 }
 type NSOpenGLContext = *mut std::ffi::c_void;
@@ -0,0 +1,309 @@
+//! Checking the version of the glfw library we are running against.
+//!
+//! The bindings are generated from the glfw 3.4 headers (or 3.3 in `glfw-3-3` mode), but a
+//! shared library picked up at runtime (or loaded with `dlopen`) may be older.
+//! [`check_version`](crate::version::check_version) compares `glfwGetVersion` with the `GLFW_VERSION_*` constants, parses `glfwGetVersionString`
+//! and reports the entry points that the runtime library doesn't have.
+//! With the `dlopen` feature, `check_version_with` does the same for a library loaded
+//! with `dynamic::GlfwApi`.
+//!
+//! ```no_run
+//! let check = glfw_sys::version::check_version();
+//! if !check.is_compatible() {
+//!     eprintln!(
+//!         "compiled against glfw {}, but running with {}",
+//!         check.compiled, check.runtime
+//!     );
+//!     eprintln!("don't call {:?}", check.unavailable);
+//! }
+//! ```
+use crate::*;
+use std::ffi::{c_char, c_int, CStr};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// A glfw version, ordered by major, minor and revision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub major: i32,
+    pub minor: i32,
+    pub revision: i32,
+}
+impl Version {
+    /// The version of the headers that the bindings were generated from.
+    pub const COMPILED: Self = Self::new(
+        GLFW_VERSION_MAJOR,
+        GLFW_VERSION_MINOR,
+        GLFW_VERSION_REVISION,
+    );
+    pub const fn new(major: i32, minor: i32, revision: i32) -> Self {
+        Self {
+            major,
+            minor,
+            revision,
+        }
+    }
+    /// The version of the glfw library we are running against, from `glfwGetVersion`.
+    pub fn runtime() -> Self {
+        Self::from_fn(glfwGetVersion)
+    }
+    /// The version reported by `glfwGetVersion` of the linked (or loaded) library.
+    fn from_fn(get_version: unsafe extern "C" fn(*mut c_int, *mut c_int, *mut c_int)) -> Self {
+        let (mut major, mut minor, mut revision) = (0, 0, 0);
+        // Safety: glfwGetVersion can be called at any time, from any thread.
+        unsafe { get_version(&mut major, &mut minor, &mut revision) };
+        Self::new(major, minor, revision)
+    }
+    /// The entry points of the bindings that glfw libraries of this version don't have.
+    pub fn missing_entry_points(self) -> Vec<&'static str> {
+        ENTRY_POINTS
+            .iter()
+            .filter(|(_, since)| self < *since)
+            .map(|(name, _)| *name)
+            .collect()
+    }
+}
+impl Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.revision)
+    }
+}
+impl FromStr for Version {
+    type Err = InvalidVersion;
+    /// Parses `major.minor.revision` (the revision is optional).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidVersion(s.to_string());
+        let mut parts = s.split('.').map(|part| part.parse().map_err(|_| invalid()));
+        let major = parts.next().ok_or_else(invalid)??;
+        let minor = parts.next().ok_or_else(invalid)??;
+        let revision = parts.next().transpose()?.unwrap_or(0);
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+        Ok(Self::new(major, minor, revision))
+    }
+}
+
+/// A string that is not a `major.minor.revision` version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidVersion(pub String);
+impl Display for InvalidVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid glfw version {:?}", self.0)
+    }
+}
+impl std::error::Error for InvalidVersion {}
+
+/// Entry points added after glfw 3.2, with the version that added them.
+///
+/// Native functions use the same cfgs as their bindings in `sys/manual.rs`.
+const ENTRY_POINTS: &[(&str, Version)] = &[
+    ("glfwInitHint", Version::new(3, 3, 0)),
+    ("glfwGetError", Version::new(3, 3, 0)),
+    ("glfwGetMonitorWorkarea", Version::new(3, 3, 0)),
+    ("glfwGetMonitorContentScale", Version::new(3, 3, 0)),
+    ("glfwSetMonitorUserPointer", Version::new(3, 3, 0)),
+    ("glfwGetMonitorUserPointer", Version::new(3, 3, 0)),
+    ("glfwGetWindowContentScale", Version::new(3, 3, 0)),
+    ("glfwGetWindowOpacity", Version::new(3, 3, 0)),
+    ("glfwSetWindowOpacity", Version::new(3, 3, 0)),
+    ("glfwRequestWindowAttention", Version::new(3, 3, 0)),
+    ("glfwSetWindowAttrib", Version::new(3, 3, 0)),
+    ("glfwSetWindowMaximizeCallback", Version::new(3, 3, 0)),
+    ("glfwSetWindowContentScaleCallback", Version::new(3, 3, 0)),
+    ("glfwRawMouseMotionSupported", Version::new(3, 3, 0)),
+    ("glfwGetKeyScancode", Version::new(3, 3, 0)),
+    ("glfwGetJoystickHats", Version::new(3, 3, 0)),
+    ("glfwGetJoystickGUID", Version::new(3, 3, 0)),
+    ("glfwSetJoystickUserPointer", Version::new(3, 3, 0)),
+    ("glfwGetJoystickUserPointer", Version::new(3, 3, 0)),
+    ("glfwJoystickIsGamepad", Version::new(3, 3, 0)),
+    ("glfwUpdateGamepadMappings", Version::new(3, 3, 0)),
+    ("glfwGetGamepadName", Version::new(3, 3, 0)),
+    ("glfwGetGamepadState", Version::new(3, 3, 0)),
+    #[cfg(all(
+        not(target_os = "macos"),
+        not(target_os = "windows"),
+        not(target_os = "emscripten"),
+        feature = "x11",
+        feature = "native-handles"
+    ))]
+    ("glfwSetX11SelectionString", Version::new(3, 3, 0)),
+    #[cfg(all(
+        not(target_os = "macos"),
+        not(target_os = "windows"),
+        not(target_os = "emscripten"),
+        feature = "x11",
+        feature = "native-handles"
+    ))]
+    ("glfwGetX11SelectionString", Version::new(3, 3, 0)),
+    #[cfg(feature = "osmesa")]
+    ("glfwGetOSMesaColorBuffer", Version::new(3, 3, 0)),
+    #[cfg(feature = "osmesa")]
+    ("glfwGetOSMesaDepthBuffer", Version::new(3, 3, 0)),
+    #[cfg(feature = "osmesa")]
+    ("glfwGetOSMesaContext", Version::new(3, 3, 0)),
+    ("glfwInitAllocator", Version::new(3, 4, 0)),
+    ("glfwInitVulkanLoader", Version::new(3, 4, 0)),
+    ("glfwGetPlatform", Version::new(3, 4, 0)),
+    ("glfwPlatformSupported", Version::new(3, 4, 0)),
+    ("glfwGetWindowTitle", Version::new(3, 4, 0)),
+    #[cfg(all(target_os = "macos", feature = "native-handles", glfw_3_4))]
+    ("glfwGetCocoaView", Version::new(3, 4, 0)),
+];
+
+/// A platform backend, as named in `glfwGetVersionString`.
+///
+/// Unlike `hints::Platform`, this is also available with glfw 3.3.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Backend {
+    Win32,
+    Cocoa,
+    Wayland,
+    X11,
+    Null,
+}
+impl Backend {
+    /// The name of the backend in `glfwGetVersionString`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Win32 => "Win32",
+            Self::Cocoa => "Cocoa",
+            Self::Wayland => "Wayland",
+            Self::X11 => "X11",
+            Self::Null => "Null",
+        }
+    }
+    /// The backend named `name` in `glfwGetVersionString`.
+    ///
+    /// Case insensitive, as glfw 3.3 calls the null backend `null`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        [
+            Self::Win32,
+            Self::Cocoa,
+            Self::Wayland,
+            Self::X11,
+            Self::Null,
+        ]
+        .into_iter()
+        .find(|backend| backend.name().eq_ignore_ascii_case(name))
+    }
+}
+impl Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+#[cfg(glfw_3_4)]
+impl From<Backend> for crate::hints::Platform {
+    fn from(backend: Backend) -> Self {
+        match backend {
+            Backend::Win32 => Self::Win32,
+            Backend::Cocoa => Self::Cocoa,
+            Backend::Wayland => Self::Wayland,
+            Backend::X11 => Self::X11,
+            Backend::Null => Self::Null,
+        }
+    }
+}
+
+/// The parts of `glfwGetVersionString` (eg: `3.4.0 X11 GLX Wayland Null EGL OSMesa monotonic shared`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionString {
+    /// `None` if the first word is not a version.
+    pub version: Option<Version>,
+    /// The platform backends compiled into the library.
+    pub backends: Vec<Backend>,
+    /// The other compile-time flags, in order (eg: `GLX`, `EGL`, `OSMesa`, `monotonic`, `shared`).
+    pub flags: Vec<String>,
+}
+impl VersionString {
+    pub fn parse(version_string: &str) -> Self {
+        let mut words = version_string.split_whitespace();
+        let version = words.next().and_then(|word| word.parse().ok());
+        let mut backends = vec![];
+        let mut flags = vec![];
+        for word in words {
+            match Backend::from_name(word) {
+                Some(backend) => backends.push(backend),
+                None => flags.push(word.to_string()),
+            }
+        }
+        Self {
+            version,
+            backends,
+            flags,
+        }
+    }
+    /// Parses `glfwGetVersionString` of the glfw library we are running against.
+    pub fn runtime() -> Self {
+        Self::from_fn(glfwGetVersionString)
+    }
+    /// Parses `glfwGetVersionString` of the linked (or loaded) library.
+    fn from_fn(get_version_string: unsafe extern "C" fn() -> *const c_char) -> Self {
+        // Safety: glfwGetVersionString can be called at any time, from any thread,
+        // and returns a static string.
+        let version_string = unsafe { CStr::from_ptr(get_version_string()) };
+        Self::parse(&version_string.to_string_lossy())
+    }
+}
+
+/// The result of [`check_version`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionCheck {
+    /// [`Version::COMPILED`]
+    pub compiled: Version,
+    /// [`Version::runtime`]
+    pub runtime: Version,
+    /// [`VersionString::runtime`]
+    pub version_string: VersionString,
+    /// Entry points that are unsafe to call, as the runtime library doesn't have them.
+    pub unavailable: Vec<&'static str>,
+}
+impl VersionCheck {
+    /// Whether the runtime library has everything the bindings expect:
+    /// the same major version and at least the compiled minor version.
+    pub fn is_compatible(&self) -> bool {
+        self.runtime.major == self.compiled.major && self.runtime.minor >= self.compiled.minor
+    }
+    /// Whether `entry_point` (eg: `glfwGetPlatform`) is safe to call.
+    pub fn is_available(&self, entry_point: &str) -> bool {
+        !self.unavailable.contains(&entry_point)
+    }
+}
+
+/// Compares the runtime glfw library with the version of the bindings.
+///
+/// This can be called before `glfwInit`.
+pub fn check_version() -> VersionCheck {
+    check(glfwGetVersion, glfwGetVersionString)
+}
+
+/// Compares a glfw library loaded at runtime with the version of the bindings.
+///
+/// The symbols that the library doesn't export (see `GlfwApi::missing_symbols`) are
+/// unavailable too. Returns `None` if the library doesn't have `glfwGetVersion` or
+/// `glfwGetVersionString` (ie: it is not a glfw 3 library).
+#[cfg(feature = "dlopen")]
+pub fn check_version_with(api: &crate::dynamic::GlfwApi) -> Option<VersionCheck> {
+    let mut check = check(api.glfwGetVersion?, api.glfwGetVersionString?);
+    for symbol in api.missing_symbols() {
+        if !check.unavailable.contains(symbol) {
+            check.unavailable.push(symbol);
+        }
+    }
+    Some(check)
+}
+
+fn check(
+    get_version: unsafe extern "C" fn(*mut c_int, *mut c_int, *mut c_int),
+    get_version_string: unsafe extern "C" fn() -> *const c_char,
+) -> VersionCheck {
+    let runtime = Version::from_fn(get_version);
+    VersionCheck {
+        compiled: Version::COMPILED,
+        runtime,
+        version_string: VersionString::from_fn(get_version_string),
+        unavailable: runtime.missing_entry_points(),
+    }
+}
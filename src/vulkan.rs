@@ -0,0 +1,171 @@
+//! [`ash`] types for glfw's vulkan functions.
+//!
+//! The bindings use opaque handles (eg: `VkInstance_T`), which must be converted to
+//! [`ash::vk`] handles. This module does the conversions, and wraps the vulkan functions
+//! of glfw with `ash` types.
+//!
+//! ```no_run
+//! use ash::vk;
+//! use glfw_sys::vulkan::*;
+//! use glfw_sys::*;
+//! unsafe {
+//!     assert_eq!(glfwInit(), GLFW_TRUE);
+//!     // use the same vulkan loader as glfw.
+//!     let entry = entry().expect("vulkan is not available");
+//!     let extensions: Vec<_> = required_instance_extensions()
+//!         .iter()
+//!         .map(|name| name.as_ptr())
+//!         .collect();
+//!     let create_info = vk::InstanceCreateInfo::default().enabled_extension_names(&extensions);
+//!     let instance = entry.create_instance(&create_info, None).unwrap();
+//!     glfwWindowHint(GLFW_CLIENT_API, GLFW_NO_API);
+//!     let window = glfwCreateWindow(800, 600, c"vulkan".as_ptr(), std::ptr::null_mut(), std::ptr::null_mut());
+//!     let surface = create_window_surface(instance.handle(), window, None).unwrap();
+//!     // ...
+//!     ash::khr::surface::Instance::new(&entry, &instance).destroy_surface(surface, None);
+//!     instance.destroy_instance(None);
+//!     glfwDestroyWindow(window);
+//!     glfwTerminate();
+//! }
+//! ```
+use crate::*;
+use ash::vk::{self, Handle};
+use std::ffi::CStr;
+
+/// Converts an `ash` instance to the handle used by the bindings.
+pub fn to_raw_instance(instance: vk::Instance) -> VkInstance {
+    instance.as_raw() as VkInstance
+}
+/// Converts an instance handle of the bindings to `ash`.
+pub fn from_raw_instance(instance: VkInstance) -> vk::Instance {
+    vk::Instance::from_raw(instance as u64)
+}
+/// Converts an `ash` physical device to the handle used by the bindings.
+pub fn to_raw_physical_device(device: vk::PhysicalDevice) -> VkPhysicalDevice {
+    device.as_raw() as VkPhysicalDevice
+}
+/// Converts a physical device handle of the bindings to `ash`.
+pub fn from_raw_physical_device(device: VkPhysicalDevice) -> vk::PhysicalDevice {
+    vk::PhysicalDevice::from_raw(device as u64)
+}
+/// Converts an `ash` surface to the handle used by the bindings.
+///
+/// Only on 64-bit targets. `VkSurfaceKHR` is a non-dispatchable handle, which vulkan.h
+/// defines as a `uint64_t` on 32-bit targets, but the bindings use a pointer.
+#[cfg(target_pointer_width = "64")]
+pub fn to_raw_surface(surface: vk::SurfaceKHR) -> VkSurfaceKHR {
+    surface.as_raw() as VkSurfaceKHR
+}
+/// Converts a surface handle of the bindings to `ash`.
+///
+/// Only on 64-bit targets (see [`to_raw_surface`]).
+#[cfg(target_pointer_width = "64")]
+pub fn from_raw_surface(surface: VkSurfaceKHR) -> vk::SurfaceKHR {
+    vk::SurfaceKHR::from_raw(surface as u64)
+}
+
+/// The instance extensions that glfw needs to create window surfaces.
+///
+/// Returns an empty list if vulkan is not available (see `glfwVulkanSupported`).
+///
+/// # Safety
+/// glfw must be initialized. The strings are valid until `glfwTerminate`.
+pub unsafe fn required_instance_extensions() -> Vec<&'static CStr> {
+    let mut count = 0;
+    let names = glfwGetRequiredInstanceExtensions(&mut count);
+    if names.is_null() {
+        return vec![];
+    }
+    std::slice::from_raw_parts(names, count as usize)
+        .iter()
+        .map(|&name| CStr::from_ptr(name))
+        .collect()
+}
+
+/// Whether `queue_family` of `device` can present images to the platform that glfw uses.
+///
+/// # Safety
+/// glfw must be initialized, and `device` must belong to `instance`.
+pub unsafe fn physical_device_presentation_support(
+    instance: vk::Instance,
+    device: vk::PhysicalDevice,
+    queue_family: u32,
+) -> bool {
+    glfwGetPhysicalDevicePresentationSupport(
+        to_raw_instance(instance),
+        to_raw_physical_device(device),
+        queue_family,
+    ) == GLFW_TRUE
+}
+
+/// Creates a vulkan surface for `window`.
+///
+/// The window must have been created with `GLFW_CLIENT_API` set to `GLFW_NO_API`,
+/// and `instance` must have the [`required_instance_extensions`] enabled.
+///
+/// # Safety
+/// Same as `glfwCreateWindowSurface`.
+pub unsafe fn create_window_surface(
+    instance: vk::Instance,
+    window: *mut GLFWwindow,
+    allocator: Option<&vk::AllocationCallbacks>,
+) -> Result<vk::SurfaceKHR, vk::Result> {
+    // `VkSurfaceKHR` is always 64 bits (a `uint64_t` on 32-bit targets), but the bindings
+    // use a pointer. So, glfw must write into a `vk::SurfaceKHR`.
+    let mut surface = vk::SurfaceKHR::null();
+    let allocator = allocator.map_or(std::ptr::null(), |allocator| {
+        // both are the `VkAllocationCallbacks` struct of vulkan.h.
+        allocator as *const vk::AllocationCallbacks as *const VkAllocationCallbacks
+    });
+    let result = glfwCreateWindowSurface(
+        to_raw_instance(instance),
+        window,
+        allocator,
+        &mut surface as *mut vk::SurfaceKHR as *mut VkSurfaceKHR,
+    );
+    vk::Result::from_raw(result).result_with_success(surface)
+}
+
+/// Returns the address of the vulkan function `name` for `instance`
+/// (or of the loader, if `instance` is null).
+///
+/// # Safety
+/// glfw must be initialized.
+pub unsafe fn instance_proc_address(instance: vk::Instance, name: &CStr) -> vk::PFN_vkVoidFunction {
+    // the bindings use the "C" ABI, but vulkan functions use the "system" ABI (stdcall on 32-bit windows).
+    std::mem::transmute(glfwGetInstanceProcAddress(
+        to_raw_instance(instance),
+        name.as_ptr(),
+    ))
+}
+
+/// An [`ash::Entry`] that uses the vulkan loader found by glfw.
+///
+/// Returns `None` if vulkan is not available (see `glfwVulkanSupported`).
+///
+/// # Safety
+/// glfw must be initialized. The entry must not be used after `glfwTerminate`,
+/// as glfw unloads the vulkan loader.
+pub unsafe fn entry() -> Option<ash::Entry> {
+    let get_instance_proc_addr =
+        instance_proc_address(vk::Instance::null(), c"vkGetInstanceProcAddr")?;
+    Some(ash::Entry::from_static_fn(ash::StaticFn {
+        get_instance_proc_addr: std::mem::transmute::<
+            unsafe extern "system" fn(),
+            vk::PFN_vkGetInstanceProcAddr,
+        >(get_instance_proc_addr),
+    }))
+}
+
+/// Makes glfw use the vulkan loader of `entry`, instead of loading its own.
+///
+/// # Safety
+/// Same as `glfwInitVulkanLoader`: must be called before `glfwInit`.
+/// `entry` (and the library it loaded) must outlive glfw.
+#[cfg(glfw_3_4)]
+pub unsafe fn init_vulkan_loader(entry: &ash::Entry) {
+    glfwInitVulkanLoader(std::mem::transmute::<
+        vk::PFN_vkGetInstanceProcAddr,
+        PFN_vkGetInstanceProcAddr,
+    >(entry.static_fn().get_instance_proc_addr));
+}
@@ -0,0 +1,180 @@
+//! [`raw-window-handle`](raw_window_handle) 0.6 support for glfw windows.
+//!
+//! glfw can be compiled with multiple platforms (eg: x11 and wayland) and picks one of them
+//! during `glfwInit`. So, the native handle functions we need to call depend on
+//! `glfwGetPlatform()` at runtime, not just the target os.
+//! [`raw_window_handle`](crate::window_handle::raw_window_handle) and
+//! [`raw_display_handle`](crate::window_handle::raw_display_handle) do that dispatch for you.
+//!
+//! [`GlfwWindowHandle`](crate::window_handle::GlfwWindowHandle) wraps a window and implements
+//! [`HasWindowHandle`](raw_window_handle::HasWindowHandle) and
+//! [`HasDisplayHandle`](raw_window_handle::HasDisplayHandle),
+//! for APIs that take those traits (eg: `wgpu::Instance::create_surface`).
+//!
+//! ```no_run
+//! use glfw_sys::*;
+//! use raw_window_handle::HasWindowHandle;
+//! unsafe {
+//!     assert_eq!(glfwInit(), GLFW_TRUE);
+//!     glfwWindowHint(GLFW_CLIENT_API, GLFW_NO_API);
+//!     let window = glfwCreateWindow(800, 600, c"handles".as_ptr(), std::ptr::null_mut(), std::ptr::null_mut());
+//!     let handles = glfw_sys::window_handle::GlfwWindowHandle::new(window);
+//!     println!("{:?}", handles.window_handle().unwrap());
+//!     glfwDestroyWindow(window);
+//!     glfwTerminate();
+//! }
+//! ```
+//!
+//! This module links the native handle functions of glfw, so it can't be used with `dlopen`.
+#![allow(
+    clippy::unnecessary_cast,
+    reason = "native types are `c_void` in manual.rs, but real types (eg: `wl_display`) with bindgen"
+)]
+use crate::*;
+use raw_window_handle::{
+    DisplayHandle, HandleError, HasDisplayHandle, HasWindowHandle, RawDisplayHandle,
+    RawWindowHandle, WindowHandle,
+};
+#[allow(unused_imports, reason = "not used on emscripten target")]
+use std::{ffi::c_void, num::NonZeroIsize, ptr::NonNull};
+
+/// Returns the native handle of `window` for the platform that glfw is currently using.
+///
+/// Returns [`HandleError::NotSupported`] if the platform is not supported by this crate
+/// (eg: null platform or the feature for the platform is disabled), and
+/// [`HandleError::Unavailable`] if glfw failed to return the handle.
+///
+/// # Safety
+/// glfw must be initialized and `window` must be a valid window.
+#[allow(
+    unused_variables,
+    reason = "window is unused if no platform is enabled"
+)]
+pub unsafe fn raw_window_handle(window: *mut GLFWwindow) -> Result<RawWindowHandle, HandleError> {
+    match glfwGetPlatform() {
+        #[cfg(target_os = "windows")]
+        GLFW_PLATFORM_WIN32 => {
+            let hwnd = glfwGetWin32Window(window) as *mut c_void;
+            let hwnd_value = NonZeroIsize::new(hwnd as isize).ok_or(HandleError::Unavailable)?;
+            let mut handle = raw_window_handle::Win32WindowHandle::new(hwnd_value);
+            handle.hinstance = NonZeroIsize::new(win32::window_instance(hwnd));
+            Ok(handle.into())
+        }
+        #[cfg(target_os = "macos")]
+        GLFW_PLATFORM_COCOA => {
+            let view = glfwGetCocoaView(window) as *mut c_void;
+            let view = NonNull::new(view).ok_or(HandleError::Unavailable)?;
+            Ok(raw_window_handle::AppKitWindowHandle::new(view).into())
+        }
+        #[cfg(all(
+            not(any(target_os = "windows", target_os = "macos", target_os = "emscripten")),
+            feature = "x11"
+        ))]
+        GLFW_PLATFORM_X11 => match glfwGetX11Window(window) as std::ffi::c_ulong {
+            0 => Err(HandleError::Unavailable),
+            x11_window => Ok(raw_window_handle::XlibWindowHandle::new(x11_window).into()),
+        },
+        #[cfg(all(
+            not(any(target_os = "windows", target_os = "macos", target_os = "emscripten")),
+            feature = "wayland"
+        ))]
+        GLFW_PLATFORM_WAYLAND => {
+            let surface = glfwGetWaylandWindow(window) as *mut c_void;
+            let surface = NonNull::new(surface).ok_or(HandleError::Unavailable)?;
+            Ok(raw_window_handle::WaylandWindowHandle::new(surface).into())
+        }
+        // glfwGetPlatform returns zero if glfw is not initialized.
+        0 => Err(HandleError::Unavailable),
+        _ => Err(HandleError::NotSupported),
+    }
+}
+
+/// Returns the native display handle for the platform that glfw is currently using.
+///
+/// The errors are the same as [`raw_window_handle()`].
+///
+/// # Safety
+/// glfw must be initialized.
+pub unsafe fn raw_display_handle() -> Result<RawDisplayHandle, HandleError> {
+    match glfwGetPlatform() {
+        #[cfg(target_os = "windows")]
+        GLFW_PLATFORM_WIN32 => Ok(raw_window_handle::WindowsDisplayHandle::new().into()),
+        #[cfg(target_os = "macos")]
+        GLFW_PLATFORM_COCOA => Ok(raw_window_handle::AppKitDisplayHandle::new().into()),
+        #[cfg(all(
+            not(any(target_os = "windows", target_os = "macos", target_os = "emscripten")),
+            feature = "x11"
+        ))]
+        GLFW_PLATFORM_X11 => {
+            let display = glfwGetX11Display() as *mut c_void;
+            let display = NonNull::new(display).ok_or(HandleError::Unavailable)?;
+            // glfw opens the display with `XOpenDisplay(NULL)` and always uses its default screen.
+            // glfw doesn't expose it and we don't link xlib, so, we assume the usual screen 0.
+            Ok(raw_window_handle::XlibDisplayHandle::new(Some(display), 0).into())
+        }
+        #[cfg(all(
+            not(any(target_os = "windows", target_os = "macos", target_os = "emscripten")),
+            feature = "wayland"
+        ))]
+        GLFW_PLATFORM_WAYLAND => {
+            let display = glfwGetWaylandDisplay() as *mut c_void;
+            let display = NonNull::new(display).ok_or(HandleError::Unavailable)?;
+            Ok(raw_window_handle::WaylandDisplayHandle::new(display).into())
+        }
+        0 => Err(HandleError::Unavailable),
+        _ => Err(HandleError::NotSupported),
+    }
+}
+
+/// A glfw window that implements [`HasWindowHandle`] and [`HasDisplayHandle`].
+///
+/// This doesn't own the window. It is up to you to keep the window (and glfw) alive
+/// while the handles are in use.
+#[derive(Debug, Clone, Copy)]
+pub struct GlfwWindowHandle {
+    window: *mut GLFWwindow,
+}
+impl GlfwWindowHandle {
+    /// # Safety
+    /// `window` must be a valid window, and must outlive this struct
+    /// and any handles borrowed from it.
+    pub unsafe fn new(window: *mut GLFWwindow) -> Self {
+        Self { window }
+    }
+    /// The wrapped window
+    pub fn window(&self) -> *mut GLFWwindow {
+        self.window
+    }
+}
+impl HasWindowHandle for GlfwWindowHandle {
+    fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+        // Safety: `new` requires the window to be valid for the lifetime of self.
+        unsafe { Ok(WindowHandle::borrow_raw(raw_window_handle(self.window)?)) }
+    }
+}
+impl HasDisplayHandle for GlfwWindowHandle {
+    fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        // Safety: a valid window means glfw is initialized.
+        unsafe { Ok(DisplayHandle::borrow_raw(raw_display_handle()?)) }
+    }
+}
+
+/// glfw doesn't expose the `HINSTANCE` it registered the window class with,
+/// so we get it from the window itself.
+#[cfg(target_os = "windows")]
+mod win32 {
+    use std::ffi::{c_int, c_void};
+    const GWLP_HINSTANCE: c_int = -6;
+    #[link(name = "user32")]
+    extern "system" {
+        #[cfg(target_pointer_width = "64")]
+        fn GetWindowLongPtrW(hwnd: *mut c_void, index: c_int) -> isize;
+        // `GetWindowLongPtrW` is just a macro for `GetWindowLongW` on 32-bit windows.
+        #[cfg(target_pointer_width = "32")]
+        #[link_name = "GetWindowLongW"]
+        fn GetWindowLongPtrW(hwnd: *mut c_void, index: c_int) -> isize;
+    }
+    pub unsafe fn window_instance(hwnd: *mut c_void) -> isize {
+        GetWindowLongPtrW(hwnd, GWLP_HINSTANCE)
+    }
+}
@@ -0,0 +1,65 @@
+//! glfw allocations through Rust's global allocator.
+mod common;
+
+use glfw_sys::allocator::*;
+use glfw_sys::*;
+
+#[test]
+fn allocator_functions() {
+    static COUNTERS: AllocationCounters = AllocationCounters::new();
+    let allocator = rust_allocator(&COUNTERS);
+    let (allocate, reallocate, deallocate) = (
+        allocator.allocate.unwrap(),
+        allocator.reallocate.unwrap(),
+        allocator.deallocate.unwrap(),
+    );
+    unsafe {
+        let block = allocate(100, allocator.user) as *mut u8;
+        assert!(!block.is_null());
+        assert_eq!(block as usize % 16, 0);
+        for i in 0..100 {
+            block.add(i).write(i as u8);
+        }
+        let block = reallocate(block.cast(), 1000, allocator.user) as *mut u8;
+        assert!(!block.is_null());
+        for i in 0..100 {
+            assert_eq!(block.add(i).read(), i as u8);
+        }
+        let block = reallocate(block.cast(), 10, allocator.user) as *mut u8;
+        assert_eq!(block.add(9).read(), 9);
+        deallocate(block.cast(), allocator.user);
+    }
+    assert_eq!(
+        COUNTERS.stats(),
+        AllocationStats {
+            allocations: 1,
+            allocated_bytes: 100,
+            reallocations: 2,
+            reallocated_bytes: 1010,
+            deallocations: 1,
+            deallocated_bytes: 10,
+            live_bytes: 0,
+            peak_bytes: 1000,
+        }
+    );
+}
+
+#[test]
+fn glfw_uses_rust_allocator() {
+    let _glfw = common::lock();
+    unsafe {
+        init_rust_allocator();
+        glfwInitHint(GLFW_PLATFORM, GLFW_PLATFORM_NULL);
+        assert_eq!(glfwInit(), GLFW_TRUE);
+        let window = common::create_window(640, 480);
+        let stats = GLFW_ALLOCATIONS.stats();
+        assert!(stats.allocations > 0);
+        assert!(stats.live_bytes > 0);
+        glfwDestroyWindow(window);
+        glfwTerminate();
+        // glfw frees everything on terminate.
+        assert_eq!(GLFW_ALLOCATIONS.stats().live_bytes, 0);
+        glfwInitAllocator(std::ptr::null());
+    }
+    common::assert_no_error();
+}
@@ -0,0 +1,387 @@
+//! Compares the bindings generated by bindgen at build time against the pre-generated
+//! bindings in `src/sys/pregenerated.rs` and the manually maintained ones in `src/sys/manual.rs`.
+//!
+//! Run with `cargo test --features=bindgen,src-build --test=binding_drift`.
+//! It reports missing/extra items, function signature mismatches, constant value differences
+//! and struct layout differences.
+//!
+//! If `GLFW_BINDGEN_3_3_HEADER` points to the `glfw3.h` of glfw 3.3, `src/sys/pregenerated_3_3.rs`
+//! is checked too (including the docs of functions, as it is easy to mix them up with 3.4 docs).
+#![cfg(feature = "bindgen")]
+
+use quote::ToTokens;
+use std::collections::BTreeMap;
+use std::ffi::c_long;
+use syn::punctuated::Punctuated;
+
+const GENERATED: &str = include_str!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+const PREGENERATED: &str = include_str!("../src/sys/pregenerated.rs");
+const MANUAL: &str = include_str!("../src/sys/manual.rs");
+#[cfg(glfw_bindgen_3_3)]
+const GENERATED_3_3: &str = include_str!(concat!(env!("OUT_DIR"), "/bindings_3_3.rs"));
+#[cfg(glfw_bindgen_3_3)]
+const PREGENERATED_3_3: &str = include_str!("../src/sys/pregenerated_3_3.rs");
+
+/// Features that affect the `cfg` attributes in `manual.rs`.
+const FEATURES: &[(&str, bool)] = &[
+    ("x11", cfg!(feature = "x11")),
+    ("wayland", cfg!(feature = "wayland")),
+    ("native-handles", cfg!(feature = "native-handles")),
+    ("native-gl", cfg!(feature = "native-gl")),
+    ("native-egl", cfg!(feature = "native-egl")),
+    ("osmesa", cfg!(feature = "osmesa")),
+    ("vulkan", cfg!(feature = "vulkan")),
+];
+
+/// The items of a bindings file that we compare, keyed by name.
+#[derive(Default)]
+struct Items {
+    /// `type = value`
+    consts: BTreeMap<String, String>,
+    /// parameter types and return type (parameter names don't matter)
+    fns: BTreeMap<String, syn::Signature>,
+    /// doc comments of functions
+    docs: BTreeMap<String, String>,
+    /// repr attribute and `name: type` of every field
+    structs: BTreeMap<String, String>,
+    /// target of type aliases (includes callback signatures)
+    types: BTreeMap<String, syn::Type>,
+}
+impl Items {
+    /// Collects all items from a file. Items with a `cfg` that is disabled
+    /// for the current target/features are skipped.
+    fn parse(source: &str) -> Self {
+        let file = syn::parse_file(source).expect("failed to parse bindings");
+        let mut items = Self::default();
+        for item in file.items {
+            match item {
+                syn::Item::Const(item) => {
+                    items.consts.insert(
+                        item.ident.to_string(),
+                        format!("{} = {}", tokens(&item.ty), tokens(&item.expr)),
+                    );
+                }
+                syn::Item::Struct(item) => {
+                    let repr = item
+                        .attrs
+                        .iter()
+                        .filter(|attr| attr.path().is_ident("repr"))
+                        .map(tokens)
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    let fields = item
+                        .fields
+                        .iter()
+                        .map(|field| {
+                            let name = field.ident.as_ref().map(ToString::to_string);
+                            format!("{}: {}", name.unwrap_or_default(), tokens(&field.ty))
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    items
+                        .structs
+                        .insert(item.ident.to_string(), format!("{repr} {{ {fields} }}"));
+                }
+                syn::Item::Type(item) => {
+                    items.types.insert(item.ident.to_string(), *item.ty);
+                }
+                syn::Item::ForeignMod(block) if cfg_enabled(&block.attrs) => {
+                    for item in block.items {
+                        if let syn::ForeignItem::Fn(item) = item {
+                            let name = item.sig.ident.to_string();
+                            items.docs.insert(name.clone(), docs(&item.attrs));
+                            items.fns.insert(name, item.sig);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        items
+    }
+}
+
+/// Token string of an item with std/core ffi paths stripped, as different bindgen
+/// versions (or hand-written bindings) may spell `c_int` differently.
+fn tokens(item: &impl ToTokens) -> String {
+    let mut tokens = item.to_token_stream().to_string();
+    for prefix in [
+        ":: std :: os :: raw :: ",
+        ":: core :: ffi :: ",
+        ":: std :: ffi :: ",
+        "std :: ffi :: ",
+        ":: std :: option :: ",
+        ":: core :: option :: ",
+    ] {
+        tokens = tokens.replace(prefix, "");
+    }
+    tokens
+}
+
+/// The text of all `#[doc]` attributes.
+fn docs(attrs: &[syn::Attribute]) -> String {
+    attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(doc) if doc.path.is_ident("doc") => Some(tokens(&doc.value)),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Parameter types and return type of a function.
+fn signature(sig: &syn::Signature) -> String {
+    let inputs = sig
+        .inputs
+        .iter()
+        .map(|input| match input {
+            syn::FnArg::Typed(arg) => tokens(&arg.ty),
+            syn::FnArg::Receiver(_) => unreachable!("extern functions don't have self"),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("fn({inputs}) {}", tokens(&sig.output))
+}
+
+/// ABI of a function, with type aliases resolved and integers reduced to their size.
+///
+/// manual.rs uses simplified types (eg: `*mut c_void` for `HWND`), so we can only
+/// check that the signatures are ABI compatible with the real headers.
+fn abi_signature(sig: &syn::Signature, aliases: &BTreeMap<String, syn::Type>) -> String {
+    let inputs = sig
+        .inputs
+        .iter()
+        .map(|input| match input {
+            syn::FnArg::Typed(arg) => abi(&arg.ty, aliases),
+            syn::FnArg::Receiver(_) => unreachable!("extern functions don't have self"),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let output = match &sig.output {
+        syn::ReturnType::Default => "()".to_string(),
+        syn::ReturnType::Type(_, ty) => abi(ty, aliases),
+    };
+    format!("fn({inputs}) -> {output}")
+}
+fn abi(ty: &syn::Type, aliases: &BTreeMap<String, syn::Type>) -> String {
+    let syn::Type::Path(path) = ty else {
+        return match ty {
+            syn::Type::Ptr(_) => "pointer".to_string(),
+            _ => tokens(ty),
+        };
+    };
+    let name = path
+        .path
+        .segments
+        .last()
+        .expect("empty type path")
+        .ident
+        .to_string();
+    if let Some(target) = aliases.get(&name) {
+        return abi(target, aliases);
+    }
+    let bits = match name.as_str() {
+        "c_char" | "c_schar" | "c_uchar" | "i8" | "u8" => 8,
+        "c_short" | "c_ushort" | "i16" | "u16" => 16,
+        "c_int" | "c_uint" | "i32" | "u32" => 32,
+        "c_long" | "c_ulong" => std::mem::size_of::<c_long>() * 8,
+        "c_longlong" | "c_ulonglong" | "i64" | "u64" => 64,
+        "isize" | "usize" => std::mem::size_of::<usize>() * 8,
+        _ => return name,
+    };
+    format!("int{bits}")
+}
+
+/// Evaluates `cfg` attributes like the ones in manual.rs
+fn cfg_enabled(attrs: &[syn::Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cfg"))
+        .all(|attr| cfg_meta_enabled(&attr.parse_args().expect("invalid cfg attribute")))
+}
+fn cfg_meta_enabled(meta: &syn::Meta) -> bool {
+    match meta {
+        syn::Meta::List(list) => {
+            let nested = list
+                .parse_args_with(Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated)
+                .expect("invalid cfg predicate");
+            if list.path.is_ident("all") {
+                nested.iter().all(cfg_meta_enabled)
+            } else if list.path.is_ident("any") {
+                nested.iter().any(cfg_meta_enabled)
+            } else if list.path.is_ident("not") {
+                !cfg_meta_enabled(&nested[0])
+            } else {
+                panic!("unsupported cfg predicate {}", tokens(list))
+            }
+        }
+        syn::Meta::NameValue(name_value) => {
+            let syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(value),
+                ..
+            }) = &name_value.value
+            else {
+                panic!("unsupported cfg value {}", tokens(name_value));
+            };
+            let value = value.value();
+            if name_value.path.is_ident("target_os") {
+                value == std::env::consts::OS
+            } else if name_value.path.is_ident("feature") {
+                FEATURES
+                    .iter()
+                    .find(|(name, _)| *name == value)
+                    .unwrap_or_else(|| panic!("unknown feature {value} in cfg"))
+                    .1
+            } else {
+                panic!("unsupported cfg {}", tokens(name_value))
+            }
+        }
+        syn::Meta::Path(path) => panic!("unsupported cfg {}", tokens(path)),
+    }
+}
+
+/// Without `vulkan` feature, bindgen skips everything that needs vulkan types,
+/// but the pre-generated bindings always contain them.
+fn skipped_without_vulkan(name: &str, definition: &str) -> bool {
+    !cfg!(feature = "vulkan")
+        && (name.to_lowercase().contains("vk") || definition.to_lowercase().contains("vk"))
+}
+
+/// Reports items that are only in one of the two maps, or that differ.
+fn compare<T>(
+    kind: &str,
+    generated: &BTreeMap<String, T>,
+    pregenerated: &BTreeMap<String, T>,
+    describe: impl Fn(&T) -> String,
+    problems: &mut Vec<String>,
+) {
+    for (name, item) in generated {
+        match pregenerated.get(name) {
+            None => problems.push(format!(
+                "{kind} {name} is missing from pregenerated/manual bindings"
+            )),
+            Some(existing) if describe(existing) != describe(item) => problems.push(format!(
+                "{kind} {name} differs\n    pregenerated: {}\n    bindgen:      {}",
+                describe(existing),
+                describe(item)
+            )),
+            Some(_) => {}
+        }
+    }
+    for (name, item) in pregenerated {
+        if !generated.contains_key(name) && !skipped_without_vulkan(name, &describe(item)) {
+            problems.push(format!("{kind} {name} is missing from bindgen output"));
+        }
+    }
+}
+
+/// Compares constants, structs, type aliases and `generated_fns` against the pre-generated bindings.
+fn compare_core(
+    generated: &Items,
+    generated_fns: &BTreeMap<String, syn::Signature>,
+    pregenerated: &Items,
+    problems: &mut Vec<String>,
+) {
+    compare(
+        "constant",
+        &generated.consts,
+        &pregenerated.consts,
+        Clone::clone,
+        problems,
+    );
+    compare(
+        "struct",
+        // bindgen also generates structs used by native handles (eg: wl_display).
+        &generated
+            .structs
+            .iter()
+            .filter(|(name, _)| {
+                pregenerated.structs.contains_key(*name) || name.starts_with("GLFW")
+            })
+            .map(|(name, layout)| (name.clone(), layout.clone()))
+            .collect(),
+        &pregenerated.structs,
+        Clone::clone,
+        problems,
+    );
+    compare(
+        "type",
+        &generated
+            .types
+            .iter()
+            .filter(|(name, _)| pregenerated.types.contains_key(*name) || name.starts_with("GLFW"))
+            .map(|(name, ty)| (name.clone(), ty.clone()))
+            .collect(),
+        &pregenerated.types,
+        tokens,
+        problems,
+    );
+    compare(
+        "function",
+        generated_fns,
+        &pregenerated.fns,
+        signature,
+        problems,
+    );
+}
+
+#[test]
+fn bindings_match_bindgen_output() {
+    let generated = Items::parse(GENERATED);
+    let pregenerated = Items::parse(PREGENERATED);
+    let manual = Items::parse(MANUAL);
+    let mut problems = vec![];
+
+    // native functions are compared against manual.rs, the rest against pregenerated.rs
+    let (generated_native, generated_core): (BTreeMap<_, _>, BTreeMap<_, _>) =
+        generated.fns.clone().into_iter().partition(|(name, _)| {
+            manual.fns.contains_key(name) && !pregenerated.fns.contains_key(name)
+        });
+    compare_core(&generated, &generated_core, &pregenerated, &mut problems);
+    for (name, sig) in &manual.fns {
+        match generated_native.get(name) {
+            None => problems.push(format!("function {name} is missing from bindgen output")),
+            Some(generated_sig) => {
+                let manual_abi = abi_signature(sig, &manual.types);
+                let generated_abi = abi_signature(generated_sig, &generated.types);
+                if manual_abi != generated_abi {
+                    problems.push(format!(
+                        "function {name} is not ABI compatible\n    manual:  {manual_abi}\n    bindgen: {generated_abi}"
+                    ));
+                }
+            }
+        }
+    }
+
+    assert!(
+        problems.is_empty(),
+        "bindings drifted from bindgen output ({} problems):\n{}",
+        problems.len(),
+        problems.join("\n")
+    );
+}
+
+#[test]
+#[cfg(glfw_bindgen_3_3)]
+fn bindings_3_3_match_bindgen_output() {
+    let generated = Items::parse(GENERATED_3_3);
+    let pregenerated = Items::parse(PREGENERATED_3_3);
+    let mut problems = vec![];
+
+    compare_core(&generated, &generated.fns, &pregenerated, &mut problems);
+    compare(
+        "function docs",
+        &generated.docs,
+        &pregenerated.docs,
+        Clone::clone,
+        &mut problems,
+    );
+
+    assert!(
+        problems.is_empty(),
+        "3.3 bindings drifted from bindgen output ({} problems):\n{}",
+        problems.len(),
+        problems.join("\n")
+    );
+}
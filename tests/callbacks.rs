@@ -0,0 +1,120 @@
+//! Rust closures as callbacks through the `callbacks` module.
+mod common;
+
+use glfw_sys::callbacks::*;
+use glfw_sys::*;
+use std::cell::Cell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::rc::Rc;
+
+#[test]
+fn window_closures() {
+    let _glfw = common::init();
+    let window = common::create_window(640, 480);
+    let sizes = Rc::new(Cell::new((0, 0)));
+    unsafe {
+        let recorded = sizes.clone();
+        let previous = set_window_size_callback(
+            window,
+            Some(Box::new(move |_, width, height| {
+                recorded.set((width, height))
+            })),
+        );
+        assert!(previous.is_none());
+        glfwSetWindowSize(window, 300, 200);
+        poll_events();
+        assert_eq!(sizes.get(), (300, 200));
+
+        // removing the closure stops the calls.
+        assert!(set_window_size_callback(window, None).is_some());
+        glfwSetWindowSize(window, 400, 300);
+        poll_events();
+        assert_eq!(sizes.get(), (300, 200));
+
+        drop_window_callbacks(window);
+        assert!(glfwGetWindowUserPointer(window).is_null());
+        glfwDestroyWindow(window);
+    }
+    // only the test owns the sizes now, so the closure was dropped.
+    assert_eq!(Rc::strong_count(&sizes), 1);
+    common::assert_no_error();
+}
+
+#[test]
+fn closure_removes_itself() {
+    let _glfw = common::init();
+    let window = common::create_window(640, 480);
+    let calls = Rc::new(Cell::new(0));
+    unsafe {
+        let counter = calls.clone();
+        set_window_size_callback(
+            window,
+            Some(Box::new(move |window, _, _| {
+                counter.set(counter.get() + 1);
+                // the closure is running, so there is nothing to return.
+                assert!(set_window_size_callback(window, None).is_none());
+            })),
+        );
+        glfwSetWindowSize(window, 300, 200);
+        poll_events();
+        assert_eq!(calls.get(), 1);
+        // the trampoline didn't put the closure back.
+        glfwSetWindowSize(window, 400, 300);
+        poll_events();
+        assert_eq!(calls.get(), 1);
+        assert!(set_window_size_callback(window, None).is_none());
+
+        drop_window_callbacks(window);
+        glfwDestroyWindow(window);
+    }
+    assert_eq!(Rc::strong_count(&calls), 1);
+    common::assert_no_error();
+}
+
+#[test]
+fn panics_are_resumed_after_events() {
+    let _glfw = common::init();
+    let window = common::create_window(640, 480);
+    let calls = Rc::new(Cell::new(0));
+    unsafe {
+        let counter = calls.clone();
+        set_window_pos_callback(
+            window,
+            Some(Box::new(move |_, _, _| {
+                counter.set(counter.get() + 1);
+                panic!("panic in pos callback");
+            })),
+        );
+        glfwSetWindowPos(window, 10, 20);
+        // the second event is skipped, as the first callback already panicked.
+        glfwSetWindowPos(window, 30, 40);
+        let payload = catch_unwind(AssertUnwindSafe(|| poll_events())).unwrap_err();
+        assert_eq!(
+            payload.downcast_ref::<&str>(),
+            Some(&"panic in pos callback")
+        );
+        assert_eq!(calls.get(), 1);
+        // nothing left to resume.
+        resume_panic();
+
+        drop_window_callbacks(window);
+        glfwDestroyWindow(window);
+    }
+    common::assert_no_error();
+}
+
+#[test]
+fn error_closure() {
+    let _glfw = common::init();
+    let codes = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    unsafe {
+        let recorded = codes.clone();
+        set_error_callback(Some(Box::new(move |code, _| {
+            recorded.lock().unwrap().push(code)
+        })));
+        glfwWindowHint(0, 0);
+        assert!(set_error_callback(None).is_some());
+    }
+    assert_eq!(*codes.lock().unwrap(), [GLFW_INVALID_ENUM]);
+    assert!(glfw_sys::error::get_error().is_some());
+}
@@ -0,0 +1,69 @@
+//! Helpers shared by the integration tests.
+//!
+//! All tests run against glfw's null platform, so they don't need a display or gpu.
+//! Run them with `cargo test --features=src-build,static-link,null-platform,log,gamecontrollerdb,ash`.
+#![allow(unused, reason = "not every test file uses every helper")]
+
+use glfw_sys::error::get_error;
+use glfw_sys::*;
+use std::sync::{Mutex, PoisonError};
+
+/// glfw is global state, and the test harness runs tests on multiple threads.
+/// So, every test must hold this lock while glfw is initialized.
+static GLFW_LOCK: Mutex<()> = Mutex::new(());
+
+/// Terminates glfw when dropped (even if the test panics).
+pub struct Glfw {
+    _guard: std::sync::MutexGuard<'static, ()>,
+}
+impl Drop for Glfw {
+    fn drop(&mut self) {
+        unsafe { glfwTerminate() };
+    }
+}
+
+/// Takes the glfw lock without initializing glfw, for tests that call `glfwInit` themselves.
+pub fn lock() -> Glfw {
+    Glfw {
+        _guard: GLFW_LOCK.lock().unwrap_or_else(PoisonError::into_inner),
+    }
+}
+
+/// Initializes glfw with the null platform.
+pub fn init() -> Glfw {
+    let glfw = lock();
+    unsafe {
+        glfwInitHint(GLFW_PLATFORM, GLFW_PLATFORM_NULL);
+        assert_eq!(glfwInit(), GLFW_TRUE, "glfwInit failed: {:?}", get_error());
+        assert_eq!(glfwGetPlatform(), GLFW_PLATFORM_NULL);
+    }
+    glfw
+}
+
+/// Creates a window without any client api, as the null platform can only create
+/// contexts via OSMesa/EGL.
+pub fn create_window(width: i32, height: i32) -> *mut GLFWwindow {
+    unsafe {
+        glfwDefaultWindowHints();
+        glfwWindowHint(GLFW_CLIENT_API, GLFW_NO_API);
+        let window = glfwCreateWindow(
+            width,
+            height,
+            c"glfw-sys test".as_ptr(),
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        );
+        assert!(
+            !window.is_null(),
+            "glfwCreateWindow failed: {:?}",
+            get_error()
+        );
+        window
+    }
+}
+
+/// Asserts that the previous glfw calls didn't generate any errors.
+#[track_caller]
+pub fn assert_no_error() {
+    assert_eq!(get_error(), None);
+}
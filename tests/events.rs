@@ -0,0 +1,41 @@
+//! Window events collected through the `events` module.
+mod common;
+
+use glfw_sys::events::{EventQueue, WindowEvent};
+use glfw_sys::*;
+
+#[test]
+fn queued_events() {
+    let _glfw = common::init();
+    let window = common::create_window(640, 480);
+    unsafe {
+        let events = EventQueue::new(window);
+        assert!(events.is_empty());
+        glfwSetWindowPos(window, 12, 34);
+        glfwSetWindowSize(window, 320, 240);
+        glfw_sys::callbacks::poll_events();
+
+        let drained = events.drain();
+        assert!(events.is_empty());
+        let received: Vec<_> = drained.iter().map(|(_, event)| event.clone()).collect();
+        assert!(
+            received.contains(&WindowEvent::Pos { x: 12, y: 34 }),
+            "{received:?}"
+        );
+        assert!(
+            received.contains(&WindowEvent::Size {
+                width: 320,
+                height: 240
+            }),
+            "{received:?}"
+        );
+        // timestamps are in order.
+        assert!(drained.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+
+        glfw_sys::callbacks::drop_window_callbacks(window);
+        glfwSetWindowPos(window, 56, 78);
+        assert!(events.pop().is_none());
+        glfwDestroyWindow(window);
+    }
+    common::assert_no_error();
+}
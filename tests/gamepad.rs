@@ -0,0 +1,75 @@
+//! Parsing and applying gamepad mappings with the `gamepad` module.
+mod common;
+
+use glfw_sys::gamepad::*;
+use glfw_sys::*;
+
+const XBOX_LINUX: &str = "030000005e0400008e02000010010000,Xbox 360 Controller,a:b0,b:b1,back:b6,dpdown:h0.4,dpleft:h0.8,dpright:h0.2,dpup:h0.1,guide:b8,leftshoulder:b4,leftstick:b9,lefttrigger:a2,leftx:a0,lefty:a1,rightshoulder:b5,rightstick:b10,righttrigger:a5,rightx:a3,righty:a4~,start:b7,x:b2,y:b3,+lefty:+a1,misc1:b11,platform:Linux,";
+
+#[test]
+fn parse_lines() {
+    let mapping = Mapping::parse(XBOX_LINUX).unwrap();
+    assert_eq!(mapping.guid, "030000005e0400008e02000010010000");
+    assert_eq!(mapping.name, "Xbox 360 Controller");
+    assert_eq!(mapping.platform, Some(Platform::Linux));
+    assert!(mapping.is_for_platform(Platform::Linux));
+    assert!(!mapping.is_for_platform(Platform::Windows));
+
+    let text = format!(
+        "# comment\n\n{XBOX_LINUX}\nnot a guid,Name,a:b0,\n{}\n{}\n{}\n",
+        XBOX_LINUX.replace("a:b0", "a:q0"),
+        XBOX_LINUX.replace("Linux", "Amiga"),
+        "030000005e0400008e02000010010000,Any Platform,a:b0,dpup:h0.3,"
+    );
+    let (mappings, errors) = parse_mappings(&text);
+    assert_eq!(mappings, [mapping]);
+    let errors: Vec<_> = errors
+        .into_iter()
+        .map(|error| (error.line_number, error.error))
+        .collect();
+    assert_eq!(
+        errors,
+        [
+            (4, MappingError::InvalidGuid("not a guid".to_string())),
+            (5, MappingError::InvalidField("a:q0".to_string())),
+            (6, MappingError::UnknownPlatform("Amiga".to_string())),
+            (7, MappingError::InvalidField("dpup:h0.3".to_string())),
+        ]
+    );
+}
+
+#[cfg(feature = "gamecontrollerdb")]
+#[test]
+fn bundled_database() {
+    let (mappings, errors) = parse_mappings(GAMECONTROLLERDB);
+    // SDL's special `xinput` mapping has no guid, and glfw rejects it. Everything else is valid.
+    assert!(
+        errors
+            .iter()
+            .all(|error| error.error == MappingError::InvalidGuid("xinput".to_string())),
+        "{errors:?}"
+    );
+    for platform in [Platform::Windows, Platform::MacOs, Platform::Linux] {
+        assert!(mappings
+            .iter()
+            .any(|mapping| mapping.platform == Some(platform)));
+    }
+    let _glfw = common::init();
+    assert!(unsafe { update_bundled_mappings() }.unwrap() > 0);
+    common::assert_no_error();
+}
+
+#[test]
+fn update_mappings() {
+    let _glfw = common::init();
+    let mapping = Mapping::parse(XBOX_LINUX).unwrap();
+    unsafe { update_gamepad_mappings(&[mapping]) }.unwrap();
+    let broken = Mapping {
+        line: "nul\0",
+        ..mapping
+    };
+    assert!(unsafe { update_gamepad_mappings(&[broken]) }.is_err());
+    common::assert_no_error();
+    // the null platform has no joysticks, so there is nothing else to check.
+    assert_eq!(unsafe { glfwJoystickPresent(GLFW_JOYSTICK_1) }, GLFW_FALSE);
+}
@@ -0,0 +1,123 @@
+//! Validation and application of the typed hint builders.
+mod common;
+
+use glfw_sys::error::ErrorCode;
+use glfw_sys::hints::*;
+use glfw_sys::*;
+
+#[test]
+fn window_hints_validation() {
+    let opengl = |major, minor| {
+        WindowHints::new()
+            .client_api(ClientApi::OpenGl)
+            .context_version(major, minor)
+    };
+    assert_eq!(WindowHints::new().validate(), Ok(()));
+    assert_eq!(
+        opengl(4, 6)
+            .opengl_profile(OpenGlProfile::Core)
+            .opengl_forward_compat(true)
+            .validate(),
+        Ok(())
+    );
+    assert_eq!(
+        opengl(2, 1).opengl_profile(OpenGlProfile::Core).validate(),
+        Err(HintError::ProfileRequiresOpenGl32 { major: 2, minor: 1 })
+    );
+    assert_eq!(
+        opengl(2, 1).opengl_forward_compat(true).validate(),
+        Err(HintError::ForwardCompatRequiresOpenGl30 { major: 2, minor: 1 })
+    );
+    assert_eq!(
+        opengl(3, 4).validate(),
+        Err(HintError::InvalidContextVersion {
+            api: ClientApi::OpenGl,
+            major: 3,
+            minor: 4
+        })
+    );
+    let gles = WindowHints::new()
+        .client_api(ClientApi::OpenGlEs)
+        .context_version(3, 2);
+    assert_eq!(gles.validate(), Ok(()));
+    assert_eq!(
+        gles.clone().opengl_forward_compat(true).validate(),
+        Err(HintError::OpenGlOnlyHint("GLFW_OPENGL_FORWARD_COMPAT"))
+    );
+    assert_eq!(
+        gles.context_version(2, 1).validate(),
+        Err(HintError::InvalidContextVersion {
+            api: ClientApi::OpenGlEs,
+            major: 2,
+            minor: 1
+        })
+    );
+    // context hints are ignored without a client api.
+    assert_eq!(
+        WindowHints::new()
+            .client_api(ClientApi::NoApi)
+            .context_version(2, 1)
+            .opengl_profile(OpenGlProfile::Core)
+            .validate(),
+        Ok(())
+    );
+    assert_eq!(
+        WindowHints::new().x11_class_name("a\0b").validate(),
+        Err(HintError::NulInString("GLFW_X11_CLASS_NAME"))
+    );
+}
+
+#[test]
+fn window_hints_apply() {
+    let _glfw = common::init();
+    unsafe {
+        // apply must reset the hints that are not part of the builder.
+        glfwWindowHint(GLFW_DECORATED, GLFW_FALSE);
+        WindowHints::new()
+            .client_api(ClientApi::NoApi)
+            .resizable(false)
+            .floating(true)
+            .position(30, 40)
+            .samples(None)
+            .x11_class_name("glfw-sys")
+            .wayland_app_id("glfw-sys")
+            .apply()
+            .unwrap();
+        let window = glfwCreateWindow(
+            640,
+            480,
+            c"hints".as_ptr(),
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        );
+        assert!(!window.is_null());
+        assert_eq!(glfwGetWindowAttrib(window, GLFW_RESIZABLE), GLFW_FALSE);
+        assert_eq!(glfwGetWindowAttrib(window, GLFW_FLOATING), GLFW_TRUE);
+        assert_eq!(glfwGetWindowAttrib(window, GLFW_DECORATED), GLFW_TRUE);
+        assert_eq!(glfwGetWindowAttrib(window, GLFW_CLIENT_API), GLFW_NO_API);
+        let (mut x, mut y) = (0, 0);
+        glfwGetWindowPos(window, &mut x, &mut y);
+        assert_eq!((x, y), (30, 40));
+        glfwDestroyWindow(window);
+    }
+    common::assert_no_error();
+}
+
+#[test]
+fn init_hints_platform_fallback() {
+    let _glfw = common::lock();
+    // the tests build glfw with only the null platform.
+    assert!(Platform::Null.is_supported());
+    assert!(!Platform::X11.is_supported());
+    let error = unsafe { InitHints::new().platform(Platform::X11).init() }.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PlatformUnavailable);
+
+    let platform = unsafe {
+        InitHints::new()
+            .platforms(&[Platform::X11, Platform::Wayland, Platform::Null])
+            .wayland_libdecor(WaylandLibdecor::Disable)
+            .init()
+    };
+    assert_eq!(platform.unwrap(), Platform::Null);
+    common::assert_no_error();
+}
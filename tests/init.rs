@@ -0,0 +1,207 @@
+//! Library level functions: init, version, errors, time, clipboard and joysticks.
+mod common;
+
+use glfw_sys::error::{get_error, ErrorCode};
+use glfw_sys::*;
+use std::ffi::CStr;
+use std::sync::atomic::{AtomicI32, Ordering};
+
+#[test]
+fn version() {
+    let _glfw = common::init();
+    let (mut major, mut minor, mut revision) = (0, 0, 0);
+    unsafe { glfwGetVersion(&mut major, &mut minor, &mut revision) };
+    assert_eq!((major, minor), (GLFW_VERSION_MAJOR, GLFW_VERSION_MINOR));
+    assert!(revision >= 0);
+
+    let version = unsafe { CStr::from_ptr(glfwGetVersionString()) }.to_string_lossy();
+    assert!(
+        version.starts_with(&format!("{major}.{minor}.{revision}")),
+        "{version}"
+    );
+    assert!(version.contains("Null"), "{version}");
+}
+
+#[test]
+fn platform() {
+    let _glfw = common::init();
+    unsafe {
+        assert_eq!(glfwGetPlatform(), GLFW_PLATFORM_NULL);
+        assert_eq!(glfwPlatformSupported(GLFW_PLATFORM_NULL), GLFW_TRUE);
+    }
+    common::assert_no_error();
+}
+
+#[test]
+fn errors() {
+    static LAST_ERROR: AtomicI32 = AtomicI32::new(GLFW_NO_ERROR);
+    unsafe extern "C" fn error_callback(code: i32, description: *const std::ffi::c_char) {
+        assert!(!description.is_null());
+        LAST_ERROR.store(code, Ordering::SeqCst);
+    }
+    let _glfw = common::init();
+    common::assert_no_error();
+    unsafe {
+        assert!(glfwSetErrorCallback(Some(error_callback)).is_none());
+        // 0 is not a valid window hint.
+        glfwWindowHint(0, 0);
+        assert_eq!(LAST_ERROR.load(Ordering::SeqCst), GLFW_INVALID_ENUM);
+        assert_eq!(
+            get_error().map(|error| error.code),
+            Some(ErrorCode::InvalidEnum)
+        );
+        // glfwGetError clears the error.
+        common::assert_no_error();
+        assert!(glfwSetErrorCallback(None).is_some());
+    }
+}
+
+#[test]
+fn time() {
+    let _glfw = common::init();
+    unsafe {
+        assert!(glfwGetTime() >= 0.0);
+        glfwSetTime(10.0);
+        let time = glfwGetTime();
+        assert!((10.0..11.0).contains(&time), "{time}");
+
+        assert!(glfwGetTimerFrequency() > 0);
+        let value = glfwGetTimerValue();
+        assert!(glfwGetTimerValue() >= value);
+    }
+    common::assert_no_error();
+}
+
+#[test]
+fn clipboard() {
+    let _glfw = common::init();
+    let window = common::create_window(640, 480);
+    unsafe {
+        glfwSetClipboardString(window, c"glfw-sys clipboard".as_ptr());
+        let clipboard = glfwGetClipboardString(window);
+        assert!(!clipboard.is_null());
+        assert_eq!(CStr::from_ptr(clipboard), c"glfw-sys clipboard");
+        glfwDestroyWindow(window);
+    }
+    common::assert_no_error();
+}
+
+#[test]
+fn joysticks() {
+    unsafe extern "C" fn joystick_callback(_jid: i32, _event: i32) {}
+    let _glfw = common::init();
+    let mut data = 42;
+    unsafe {
+        assert!(glfwSetJoystickCallback(Some(joystick_callback)).is_none());
+        // the null platform never has any joysticks.
+        for jid in GLFW_JOYSTICK_1..=GLFW_JOYSTICK_LAST {
+            assert_eq!(glfwJoystickPresent(jid), GLFW_FALSE);
+            assert_eq!(glfwJoystickIsGamepad(jid), GLFW_FALSE);
+            let mut count = -1;
+            assert!(glfwGetJoystickAxes(jid, &mut count).is_null());
+            assert_eq!(count, 0);
+            assert!(glfwGetJoystickButtons(jid, &mut count).is_null());
+            assert_eq!(count, 0);
+            assert!(glfwGetJoystickHats(jid, &mut count).is_null());
+            assert_eq!(count, 0);
+            assert!(glfwGetJoystickName(jid).is_null());
+            assert!(glfwGetJoystickGUID(jid).is_null());
+            assert!(glfwGetGamepadName(jid).is_null());
+            let mut state: GLFWgamepadstate = std::mem::zeroed();
+            assert_eq!(glfwGetGamepadState(jid, &mut state), GLFW_FALSE);
+            // user pointers can only be set on connected joysticks.
+            glfwSetJoystickUserPointer(jid, &mut data as *mut i32 as *mut _);
+            assert!(glfwGetJoystickUserPointer(jid).is_null());
+        }
+        let mapping = c"03000000de280000ff11000000000000,Steam Virtual Gamepad,a:b0,b:b1,back:b6,dpdown:h0.4,dpleft:h0.8,dpright:h0.2,dpup:h0.1,guide:b8,leftshoulder:b4,leftstick:b9,lefttrigger:a2,leftx:a0,lefty:a1,rightshoulder:b5,rightstick:b10,righttrigger:a5,rightx:a3,righty:a4,start:b7,x:b2,y:b3,";
+        assert_eq!(glfwUpdateGamepadMappings(mapping.as_ptr()), GLFW_TRUE);
+        assert!(glfwSetJoystickCallback(None).is_some());
+    }
+    common::assert_no_error();
+}
+
+#[cfg(feature = "log")]
+#[test]
+fn error_logger() {
+    use std::sync::Mutex;
+    static LOGGED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+    static CHAINED: AtomicI32 = AtomicI32::new(GLFW_NO_ERROR);
+    struct Logger;
+    impl log::Log for Logger {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            metadata.target() == "glfw"
+        }
+        fn log(&self, record: &log::Record) {
+            if self.enabled(record.metadata()) {
+                let code = record.key_values().get("code".into()).unwrap();
+                LOGGED
+                    .lock()
+                    .unwrap()
+                    .push(format!("{code}: {}", record.args()));
+            }
+        }
+        fn flush(&self) {}
+    }
+    unsafe extern "C" fn previous_callback(code: i32, _: *const std::ffi::c_char) {
+        CHAINED.store(code, Ordering::SeqCst);
+    }
+    log::set_logger(&Logger).unwrap();
+    log::set_max_level(log::LevelFilter::Error);
+
+    let _glfw = common::init();
+    unsafe {
+        glfwSetErrorCallback(Some(previous_callback));
+        assert!(glfw_sys::error::set_error_logger().is_some());
+        // installing again must not chain the logger to itself.
+        assert!(glfw_sys::error::set_error_logger().is_some());
+        glfwWindowHint(0, 0);
+        glfwSetErrorCallback(None);
+    }
+    assert_eq!(CHAINED.load(Ordering::SeqCst), GLFW_INVALID_ENUM);
+    let logged = LOGGED.lock().unwrap();
+    assert_eq!(logged.len(), 1, "{logged:?}");
+    assert!(logged[0].starts_with("INVALID_ENUM: "), "{logged:?}");
+    assert_eq!(
+        get_error().map(|error| error.code),
+        Some(ErrorCode::InvalidEnum)
+    );
+}
+
+#[test]
+fn version_check() {
+    use glfw_sys::version::*;
+
+    let check = check_version();
+    assert_eq!(check.runtime, check.compiled);
+    assert!(check.is_compatible());
+    assert!(check.unavailable.is_empty());
+    assert!(check.is_available("glfwGetPlatform"));
+    assert_eq!(check.version_string.version, Some(check.runtime));
+    assert!(check.version_string.backends.contains(&Backend::Null));
+
+    let old = VersionString::parse("3.3.9 X11 GLX EGL OSMesa clock_gettime evdev shared");
+    assert_eq!(old.version, Some(Version::new(3, 3, 9)));
+    assert_eq!(old.backends, [Backend::X11]);
+    let null = VersionString::parse("3.3.9 null OSMesa");
+    assert_eq!(null.backends, [Backend::Null]);
+    assert_eq!(null.flags, ["OSMesa"]);
+    assert_eq!(
+        glfw_sys::hints::Platform::from(Backend::X11),
+        glfw_sys::hints::Platform::X11
+    );
+    assert_eq!(
+        old.flags,
+        ["GLX", "EGL", "OSMesa", "clock_gettime", "evdev", "shared"]
+    );
+    let missing = Version::new(3, 3, 9).missing_entry_points();
+    assert!(missing.contains(&"glfwGetPlatform"));
+    assert!(missing.contains(&"glfwInitAllocator"));
+    assert!(missing.contains(&"glfwGetWindowTitle"));
+    assert!(!missing.contains(&"glfwGetError"));
+    #[cfg(feature = "osmesa")]
+    assert!(Version::new(3, 2, 1)
+        .missing_entry_points()
+        .contains(&"glfwGetOSMesaContext"));
+    assert_eq!("3.4".parse(), Ok(Version::new(3, 4, 0)));
+    assert!("3.x.0".parse::<Version>().is_err());
+}
@@ -0,0 +1,230 @@
+//! Input modes, keys, cursors, input callbacks, events and contexts.
+mod common;
+
+use glfw_sys::error::{get_error, ErrorCode};
+use glfw_sys::*;
+
+#[test]
+fn input_modes() {
+    let _glfw = common::init();
+    let window = common::create_window(640, 480);
+    unsafe {
+        for mode in [
+            GLFW_STICKY_KEYS,
+            GLFW_STICKY_MOUSE_BUTTONS,
+            GLFW_LOCK_KEY_MODS,
+        ] {
+            glfwSetInputMode(window, mode, GLFW_TRUE);
+            assert_eq!(glfwGetInputMode(window, mode), GLFW_TRUE);
+            glfwSetInputMode(window, mode, GLFW_FALSE);
+            assert_eq!(glfwGetInputMode(window, mode), GLFW_FALSE);
+        }
+        for cursor_mode in [
+            GLFW_CURSOR_HIDDEN,
+            GLFW_CURSOR_DISABLED,
+            GLFW_CURSOR_CAPTURED,
+            GLFW_CURSOR_NORMAL,
+        ] {
+            glfwSetInputMode(window, GLFW_CURSOR, cursor_mode);
+            assert_eq!(glfwGetInputMode(window, GLFW_CURSOR), cursor_mode);
+        }
+        if glfwRawMouseMotionSupported() == GLFW_TRUE {
+            glfwSetInputMode(window, GLFW_RAW_MOUSE_MOTION, GLFW_TRUE);
+            assert_eq!(glfwGetInputMode(window, GLFW_RAW_MOUSE_MOTION), GLFW_TRUE);
+        }
+        glfwDestroyWindow(window);
+    }
+    common::assert_no_error();
+}
+
+#[test]
+fn keys_and_buttons() {
+    let _glfw = common::init();
+    let window = common::create_window(640, 480);
+    unsafe {
+        for key in [GLFW_KEY_SPACE, GLFW_KEY_A, GLFW_KEY_ESCAPE, GLFW_KEY_LAST] {
+            assert_eq!(glfwGetKey(window, key), GLFW_RELEASE);
+        }
+        for button in GLFW_MOUSE_BUTTON_1..=GLFW_MOUSE_BUTTON_LAST {
+            assert_eq!(glfwGetMouseButton(window, button), GLFW_RELEASE);
+        }
+        let scancode = glfwGetKeyScancode(GLFW_KEY_A);
+        assert_ne!(scancode, -1);
+        // printable keys have names.
+        assert!(!glfwGetKeyName(GLFW_KEY_A, 0).is_null());
+        assert!(!glfwGetKeyName(GLFW_KEY_UNKNOWN, scancode).is_null());
+        glfwDestroyWindow(window);
+    }
+    common::assert_no_error();
+}
+
+#[test]
+fn cursor_position() {
+    let _glfw = common::init();
+    let window = common::create_window(640, 480);
+    unsafe {
+        // cursor can only be moved in focused windows.
+        glfwFocusWindow(window);
+        glfwSetCursorPos(window, 10.0, 20.0);
+        let (mut x, mut y) = (0.0, 0.0);
+        glfwGetCursorPos(window, &mut x, &mut y);
+        assert_eq!((x, y), (10.0, 20.0));
+        glfwDestroyWindow(window);
+    }
+    common::assert_no_error();
+}
+
+#[test]
+fn cursors() {
+    let _glfw = common::init();
+    let window = common::create_window(640, 480);
+    unsafe {
+        for shape in [
+            GLFW_ARROW_CURSOR,
+            GLFW_IBEAM_CURSOR,
+            GLFW_CROSSHAIR_CURSOR,
+            GLFW_POINTING_HAND_CURSOR,
+            GLFW_RESIZE_EW_CURSOR,
+            GLFW_RESIZE_NS_CURSOR,
+            GLFW_RESIZE_NWSE_CURSOR,
+            GLFW_RESIZE_NESW_CURSOR,
+            GLFW_RESIZE_ALL_CURSOR,
+            GLFW_NOT_ALLOWED_CURSOR,
+        ] {
+            let cursor = glfwCreateStandardCursor(shape);
+            assert!(!cursor.is_null(), "{shape:#x}");
+            glfwSetCursor(window, cursor);
+            glfwDestroyCursor(cursor);
+        }
+        let mut pixels = [255u8; 16 * 16 * 4];
+        let image = GLFWimage {
+            width: 16,
+            height: 16,
+            pixels: pixels.as_mut_ptr(),
+        };
+        let cursor = glfwCreateCursor(&image, 8, 8);
+        assert!(!cursor.is_null());
+        glfwSetCursor(window, cursor);
+        glfwSetCursor(window, std::ptr::null_mut());
+        glfwDestroyCursor(cursor);
+        glfwDestroyWindow(window);
+    }
+    common::assert_no_error();
+}
+
+#[test]
+fn callbacks() {
+    unsafe extern "C" fn key(
+        _: *mut GLFWwindow,
+        _key: i32,
+        _scancode: i32,
+        _action: i32,
+        _mods: i32,
+    ) {
+    }
+    unsafe extern "C" fn character(_: *mut GLFWwindow, _codepoint: u32) {}
+    unsafe extern "C" fn char_mods(_: *mut GLFWwindow, _codepoint: u32, _mods: i32) {}
+    unsafe extern "C" fn mouse_button(_: *mut GLFWwindow, _button: i32, _action: i32, _mods: i32) {}
+    unsafe extern "C" fn cursor_pos(_: *mut GLFWwindow, _x: f64, _y: f64) {}
+    unsafe extern "C" fn cursor_enter(_: *mut GLFWwindow, _entered: i32) {}
+    unsafe extern "C" fn scroll(_: *mut GLFWwindow, _x: f64, _y: f64) {}
+    unsafe extern "C" fn file_drop(
+        _: *mut GLFWwindow,
+        _count: i32,
+        _paths: *mut *const std::ffi::c_char,
+    ) {
+    }
+
+    let _glfw = common::init();
+    let window = common::create_window(640, 480);
+    unsafe {
+        assert!(glfwSetKeyCallback(window, Some(key)).is_none());
+        assert!(glfwSetCharCallback(window, Some(character)).is_none());
+        assert!(glfwSetCharModsCallback(window, Some(char_mods)).is_none());
+        assert!(glfwSetMouseButtonCallback(window, Some(mouse_button)).is_none());
+        assert!(glfwSetCursorPosCallback(window, Some(cursor_pos)).is_none());
+        assert!(glfwSetCursorEnterCallback(window, Some(cursor_enter)).is_none());
+        assert!(glfwSetScrollCallback(window, Some(scroll)).is_none());
+        assert!(glfwSetDropCallback(window, Some(file_drop)).is_none());
+
+        assert!(glfwSetKeyCallback(window, None).is_some());
+        assert!(glfwSetCharCallback(window, None).is_some());
+        assert!(glfwSetCharModsCallback(window, None).is_some());
+        assert!(glfwSetMouseButtonCallback(window, None).is_some());
+        assert!(glfwSetCursorPosCallback(window, None).is_some());
+        assert!(glfwSetCursorEnterCallback(window, None).is_some());
+        assert!(glfwSetScrollCallback(window, None).is_some());
+        assert!(glfwSetDropCallback(window, None).is_some());
+        glfwDestroyWindow(window);
+    }
+    common::assert_no_error();
+}
+
+#[test]
+fn events() {
+    let _glfw = common::init();
+    unsafe {
+        glfwPollEvents();
+        glfwWaitEventsTimeout(0.0);
+        glfwPostEmptyEvent();
+        glfwWaitEvents();
+    }
+    common::assert_no_error();
+}
+
+#[test]
+fn no_context() {
+    let _glfw = common::init();
+    let window = common::create_window(640, 480);
+    unsafe {
+        assert!(glfwGetCurrentContext().is_null());
+        glfwMakeContextCurrent(window);
+        assert_eq!(
+            get_error().map(|error| error.code),
+            Some(ErrorCode::NoWindowContext)
+        );
+        glfwSwapBuffers(window);
+        assert_eq!(
+            get_error().map(|error| error.code),
+            Some(ErrorCode::NoWindowContext)
+        );
+        assert!(glfwGetProcAddress(c"glClear".as_ptr()).is_none());
+        assert_eq!(
+            get_error().map(|error| error.code),
+            Some(ErrorCode::NoCurrentContext)
+        );
+        glfwDestroyWindow(window);
+    }
+}
+
+#[test]
+fn typed_input_conversions() {
+    use glfw_sys::input::*;
+    use std::ffi::c_int;
+    for &key in Key::ALL {
+        assert_eq!(Key::try_from(c_int::from(key)), Ok(key));
+        assert_eq!(key.to_string().parse::<Key>(), Ok(key));
+    }
+    for &button in MouseButton::ALL {
+        assert_eq!(MouseButton::try_from(c_int::from(button)), Ok(button));
+        assert_eq!(button.to_string().parse::<MouseButton>(), Ok(button));
+    }
+    for &action in Action::ALL {
+        assert_eq!(Action::try_from(c_int::from(action)), Ok(action));
+    }
+    assert_eq!(Key::try_from(GLFW_KEY_KP_ENTER), Ok(Key::KpEnter));
+    assert_eq!(
+        MouseButton::try_from(GLFW_MOUSE_BUTTON_1),
+        Ok(MouseButton::Left)
+    );
+    assert!(Key::try_from(1000).is_err());
+    assert!("not a key".parse::<Key>().is_err());
+
+    let mods = Modifiers::try_from(GLFW_MOD_SHIFT | GLFW_MOD_SUPER).unwrap();
+    assert!(mods.contains(Modifiers::SHIFT) && !mods.contains(Modifiers::ALT));
+    assert_eq!(mods.to_string(), "SHIFT | SUPER");
+    assert_eq!(" super|Shift ".parse::<Modifiers>(), Ok(mods));
+    assert_eq!("".parse::<Modifiers>(), Ok(Modifiers::empty()));
+    assert_eq!(c_int::from(Modifiers::all()), Modifiers::all().bits());
+    assert!(Modifiers::try_from(0x100).is_err());
+}
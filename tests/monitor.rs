@@ -0,0 +1,103 @@
+//! Monitors, video modes and gamma ramps.
+mod common;
+
+use glfw_sys::*;
+use std::ffi::CStr;
+
+#[test]
+fn monitors() {
+    let _glfw = common::init();
+    unsafe {
+        let mut count = 0;
+        let monitors = glfwGetMonitors(&mut count);
+        assert!(count >= 1);
+        let monitors = std::slice::from_raw_parts(monitors, count as usize);
+        let primary = glfwGetPrimaryMonitor();
+        assert_eq!(monitors[0], primary);
+
+        let name = glfwGetMonitorName(primary);
+        assert!(!name.is_null());
+        assert!(!CStr::from_ptr(name).is_empty());
+
+        let (mut x, mut y) = (-1, -1);
+        glfwGetMonitorPos(primary, &mut x, &mut y);
+        assert!(x >= 0 && y >= 0);
+        let (mut width, mut height) = (0, 0);
+        glfwGetMonitorWorkarea(primary, &mut x, &mut y, &mut width, &mut height);
+        assert!(width > 0 && height > 0);
+        glfwGetMonitorPhysicalSize(primary, &mut width, &mut height);
+        assert!(width > 0 && height > 0);
+        let (mut xscale, mut yscale) = (0.0, 0.0);
+        glfwGetMonitorContentScale(primary, &mut xscale, &mut yscale);
+        assert!(xscale > 0.0 && yscale > 0.0);
+    }
+    common::assert_no_error();
+}
+
+#[test]
+fn video_modes() {
+    let _glfw = common::init();
+    unsafe {
+        let monitor = glfwGetPrimaryMonitor();
+        let current = *glfwGetVideoMode(monitor);
+        assert!(current.width > 0 && current.height > 0);
+        assert!(current.redBits > 0 && current.greenBits > 0 && current.blueBits > 0);
+
+        let mut count = 0;
+        let modes = glfwGetVideoModes(monitor, &mut count);
+        assert!(count >= 1);
+        let modes = std::slice::from_raw_parts(modes, count as usize);
+        assert!(modes.iter().any(|mode| mode.width == current.width
+            && mode.height == current.height
+            && mode.refreshRate == current.refreshRate));
+    }
+    common::assert_no_error();
+}
+
+#[test]
+fn user_pointer_and_callback() {
+    unsafe extern "C" fn monitor_callback(_monitor: *mut GLFWmonitor, _event: i32) {}
+    let _glfw = common::init();
+    let mut data = 42;
+    unsafe {
+        let monitor = glfwGetPrimaryMonitor();
+        assert!(glfwGetMonitorUserPointer(monitor).is_null());
+        glfwSetMonitorUserPointer(monitor, &mut data as *mut i32 as *mut _);
+        assert_eq!(*(glfwGetMonitorUserPointer(monitor) as *mut i32), 42);
+
+        assert!(glfwSetMonitorCallback(Some(monitor_callback)).is_none());
+        assert!(glfwSetMonitorCallback(None).is_some());
+    }
+    common::assert_no_error();
+}
+
+#[test]
+fn gamma() {
+    let _glfw = common::init();
+    unsafe {
+        let monitor = glfwGetPrimaryMonitor();
+        let ramp = &*glfwGetGammaRamp(monitor);
+        assert!(ramp.size > 0);
+        let size = ramp.size as usize;
+
+        // the returned ramp is only valid until the next call, so we make our own.
+        let mut red: Vec<u16> = (0..size).map(|i| (i * 65535 / (size - 1)) as u16).collect();
+        let mut green = red.clone();
+        let mut blue = red.clone();
+        let linear = GLFWgammaramp {
+            red: red.as_mut_ptr(),
+            green: green.as_mut_ptr(),
+            blue: blue.as_mut_ptr(),
+            size: size as u32,
+        };
+        glfwSetGammaRamp(monitor, &linear);
+        let ramp = &*glfwGetGammaRamp(monitor);
+        assert_eq!(ramp.size as usize, size);
+        assert_eq!(std::slice::from_raw_parts(ramp.red, size), red);
+        assert_eq!(std::slice::from_raw_parts(ramp.green, size), green);
+        assert_eq!(std::slice::from_raw_parts(ramp.blue, size), blue);
+
+        glfwSetGamma(monitor, 1.0);
+    }
+    common::assert_no_error();
+}
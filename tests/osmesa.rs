@@ -0,0 +1,88 @@
+//! Offscreen rendering with OSMesa contexts, which need `libOSMesa` at runtime.
+#![cfg(feature = "osmesa")]
+mod common;
+
+use glfw_sys::hints::WindowHints;
+use glfw_sys::osmesa::*;
+use glfw_sys::*;
+use glow::HasContext;
+
+#[test]
+fn osmesa_readback() {
+    let _glfw = common::init();
+    unsafe {
+        let window = create_window(32, 16, &WindowHints::new().depth_bits(Some(24)))
+            .expect("OSMesa context (is libOSMesa installed?)");
+        glfwMakeContextCurrent(window);
+        let gl = glow::Context::from_loader_function_cstr(|s| {
+            glfwGetProcAddress(s.as_ptr())
+                .map(|p| p as _)
+                .unwrap_or(std::ptr::null())
+        });
+        gl.clear_color(1.0, 0.0, 0.0, 1.0);
+        gl.clear_depth_f64(1.0);
+        gl.clear(glow::COLOR_BUFFER_BIT | glow::DEPTH_BUFFER_BIT);
+        // gl's origin is the bottom left, so this is the bottom half of the image.
+        gl.enable(glow::SCISSOR_TEST);
+        gl.scissor(0, 0, 32, 8);
+        gl.clear_color(0.0, 0.0, 1.0, 1.0);
+        gl.clear_depth_f64(0.0);
+        gl.clear(glow::COLOR_BUFFER_BIT | glow::DEPTH_BUFFER_BIT);
+        drop(gl);
+        glfwMakeContextCurrent(std::ptr::null_mut());
+
+        let image = read_color_buffer(window).unwrap();
+        assert_eq!((image.width, image.height), (32, 16));
+        assert_eq!(image.pixels.len(), 32 * 16 * 4);
+        assert_eq!(image.pixel(0, 0), [255, 0, 0, 255]);
+        assert_eq!(image.pixel(31, 7), [255, 0, 0, 255]);
+        assert_eq!(image.pixel(0, 8), [0, 0, 255, 255]);
+        assert_eq!(image.pixel(31, 15), [0, 0, 255, 255]);
+        // the context we made current before reading is restored.
+        assert!(glfwGetCurrentContext().is_null());
+
+        let depth = read_depth_buffer(window).unwrap();
+        assert_eq!((depth.width, depth.height), (32, 16));
+        assert_eq!(depth.values.len(), 32 * 16);
+        assert!(depth.values[0] > depth.values[32 * 16 - 1]);
+        assert_eq!(depth.values[32 * 16 - 1], 0);
+
+        let path = std::env::temp_dir().join("glfw-sys-osmesa-readback.png");
+        image.write_png(&path).unwrap();
+        let png = std::fs::read(&path).unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(png, image.to_png());
+        std::fs::remove_file(&path).unwrap();
+
+        glfwDestroyWindow(window);
+    }
+    common::assert_no_error();
+}
+
+#[test]
+fn png_known_bytes() {
+    // a red 1x1 image. The crc/adler values are from a real encoder (python's zlib).
+    let image = RgbaImage {
+        width: 1,
+        height: 1,
+        pixels: vec![255, 0, 0, 255],
+    };
+    #[rustfmt::skip]
+    let expected: &[u8] = &[
+        0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n',
+        // IHDR: 1x1, 8 bits per channel, RGBA
+        0, 0, 0, 13, b'I', b'H', b'D', b'R',
+        0, 0, 0, 1, 0, 0, 0, 1, 8, 6, 0, 0, 0,
+        0x1f, 0x15, 0xc4, 0x89,
+        // IDAT: zlib header, a final stored block of 5 bytes, filter 0 + pixel, adler-32
+        0, 0, 0, 16, b'I', b'D', b'A', b'T',
+        0x78, 0x01, 0x01, 0x05, 0x00, 0xfa, 0xff,
+        0x00, 0xff, 0x00, 0x00, 0xff,
+        0x05, 0x00, 0x01, 0xff,
+        0xfa, 0x5c, 0x88, 0xd1,
+        // IEND
+        0, 0, 0, 0, b'I', b'E', b'N', b'D',
+        0xae, 0x42, 0x60, 0x82,
+    ];
+    assert_eq!(image.to_png(), expected);
+}
@@ -0,0 +1,55 @@
+//! ash interop. The vulkan loader may not be installed, so this mostly checks that the
+//! wrappers agree with `glfwVulkanSupported`.
+#![cfg(feature = "ash")]
+mod common;
+
+use ash::vk::{self, Handle};
+use glfw_sys::vulkan::*;
+use glfw_sys::*;
+
+#[test]
+fn handle_conversions() {
+    let instance = vk::Instance::from_raw(0x1000);
+    assert_eq!(from_raw_instance(to_raw_instance(instance)), instance);
+    let device = vk::PhysicalDevice::from_raw(0x2000);
+    assert_eq!(
+        from_raw_physical_device(to_raw_physical_device(device)),
+        device
+    );
+    #[cfg(target_pointer_width = "64")]
+    {
+        let surface = vk::SurfaceKHR::from_raw(0x3000);
+        assert_eq!(from_raw_surface(to_raw_surface(surface)), surface);
+    }
+    assert!(to_raw_instance(vk::Instance::null()).is_null());
+}
+
+#[test]
+fn vulkan_loader() {
+    let _glfw = common::init();
+    unsafe {
+        let supported = glfwVulkanSupported() == GLFW_TRUE;
+        let extensions = required_instance_extensions();
+        let entry = entry();
+        assert_eq!(entry.is_some(), supported);
+        if !supported {
+            assert!(extensions.is_empty());
+            // clear the GLFW_API_UNAVAILABLE errors.
+            while glfw_sys::error::get_error().is_some() {}
+            return;
+        }
+        common::assert_no_error();
+        // every platform needs the surface extension, plus its own.
+        assert!(extensions.contains(&c"VK_KHR_surface"));
+        let entry = entry.unwrap();
+        let available = entry.enumerate_instance_extension_properties(None).unwrap();
+        for extension in &extensions {
+            assert!(
+                available
+                    .iter()
+                    .any(|properties| properties.extension_name_as_c_str() == Ok(*extension)),
+                "{extension:?} is not available"
+            );
+        }
+    }
+}
@@ -0,0 +1,319 @@
+//! Window creation, attributes, sizes, user pointers and window callbacks.
+mod common;
+
+use glfw_sys::*;
+use std::ffi::CStr;
+
+#[test]
+fn create_and_destroy() {
+    let _glfw = common::init();
+    let window = common::create_window(640, 480);
+    unsafe {
+        assert_eq!(glfwWindowShouldClose(window), GLFW_FALSE);
+        glfwSetWindowShouldClose(window, GLFW_TRUE);
+        assert_eq!(glfwWindowShouldClose(window), GLFW_TRUE);
+        assert!(glfwGetWindowMonitor(window).is_null());
+        glfwDestroyWindow(window);
+    }
+    common::assert_no_error();
+}
+
+#[test]
+fn title() {
+    let _glfw = common::init();
+    let window = common::create_window(640, 480);
+    unsafe {
+        assert_eq!(CStr::from_ptr(glfwGetWindowTitle(window)), c"glfw-sys test");
+        glfwSetWindowTitle(window, c"new title".as_ptr());
+        assert_eq!(CStr::from_ptr(glfwGetWindowTitle(window)), c"new title");
+        glfwDestroyWindow(window);
+    }
+    common::assert_no_error();
+}
+
+#[test]
+fn position_and_size() {
+    let _glfw = common::init();
+    unsafe {
+        glfwDefaultWindowHints();
+        glfwWindowHint(GLFW_CLIENT_API, GLFW_NO_API);
+        glfwWindowHint(GLFW_POSITION_X, 30);
+        glfwWindowHint(GLFW_POSITION_Y, 40);
+        let window = glfwCreateWindow(
+            640,
+            480,
+            c"position".as_ptr(),
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        );
+        assert!(!window.is_null());
+        let (mut x, mut y) = (0, 0);
+        glfwGetWindowPos(window, &mut x, &mut y);
+        assert_eq!((x, y), (30, 40));
+        glfwSetWindowPos(window, 100, 200);
+        glfwGetWindowPos(window, &mut x, &mut y);
+        assert_eq!((x, y), (100, 200));
+
+        let (mut width, mut height) = (0, 0);
+        glfwGetWindowSize(window, &mut width, &mut height);
+        assert_eq!((width, height), (640, 480));
+        glfwSetWindowSize(window, 320, 240);
+        glfwGetWindowSize(window, &mut width, &mut height);
+        assert_eq!((width, height), (320, 240));
+        glfwGetFramebufferSize(window, &mut width, &mut height);
+        assert_eq!((width, height), (320, 240));
+
+        let (mut left, mut top, mut right, mut bottom) = (-1, -1, -1, -1);
+        glfwGetWindowFrameSize(window, &mut left, &mut top, &mut right, &mut bottom);
+        assert!(left >= 0 && top >= 0 && right >= 0 && bottom >= 0);
+
+        let (mut xscale, mut yscale) = (0.0, 0.0);
+        glfwGetWindowContentScale(window, &mut xscale, &mut yscale);
+        assert!(xscale > 0.0 && yscale > 0.0);
+        glfwDestroyWindow(window);
+    }
+    common::assert_no_error();
+}
+
+#[test]
+fn size_limits() {
+    let _glfw = common::init();
+    let window = common::create_window(640, 480);
+    unsafe {
+        // the window is resized to fit within the new limits.
+        glfwSetWindowSizeLimits(window, 800, 600, GLFW_DONT_CARE, GLFW_DONT_CARE);
+        let (mut width, mut height) = (0, 0);
+        glfwGetWindowSize(window, &mut width, &mut height);
+        assert_eq!((width, height), (800, 600));
+
+        glfwSetWindowSizeLimits(
+            window,
+            GLFW_DONT_CARE,
+            GLFW_DONT_CARE,
+            GLFW_DONT_CARE,
+            GLFW_DONT_CARE,
+        );
+        glfwSetWindowAspectRatio(window, 16, 9);
+        glfwSetWindowAspectRatio(window, GLFW_DONT_CARE, GLFW_DONT_CARE);
+        glfwDestroyWindow(window);
+    }
+    common::assert_no_error();
+}
+
+#[test]
+fn opacity_and_icon() {
+    let _glfw = common::init();
+    let window = common::create_window(640, 480);
+    unsafe {
+        assert_eq!(glfwGetWindowOpacity(window), 1.0);
+        glfwSetWindowOpacity(window, 0.5);
+        assert_eq!(glfwGetWindowOpacity(window), 0.5);
+
+        let mut pixels = [255u8; 16 * 16 * 4];
+        let icon = GLFWimage {
+            width: 16,
+            height: 16,
+            pixels: pixels.as_mut_ptr(),
+        };
+        glfwSetWindowIcon(window, 1, &icon);
+        glfwSetWindowIcon(window, 0, std::ptr::null());
+        glfwRequestWindowAttention(window);
+        glfwDestroyWindow(window);
+    }
+    common::assert_no_error();
+}
+
+#[test]
+fn states() {
+    let _glfw = common::init();
+    let window = common::create_window(640, 480);
+    unsafe {
+        assert_eq!(glfwGetWindowAttrib(window, GLFW_VISIBLE), GLFW_TRUE);
+        glfwHideWindow(window);
+        assert_eq!(glfwGetWindowAttrib(window, GLFW_VISIBLE), GLFW_FALSE);
+        assert_eq!(glfwGetWindowAttrib(window, GLFW_FOCUSED), GLFW_FALSE);
+        glfwShowWindow(window);
+        glfwFocusWindow(window);
+        assert_eq!(glfwGetWindowAttrib(window, GLFW_VISIBLE), GLFW_TRUE);
+        assert_eq!(glfwGetWindowAttrib(window, GLFW_FOCUSED), GLFW_TRUE);
+
+        glfwIconifyWindow(window);
+        assert_eq!(glfwGetWindowAttrib(window, GLFW_ICONIFIED), GLFW_TRUE);
+        glfwRestoreWindow(window);
+        assert_eq!(glfwGetWindowAttrib(window, GLFW_ICONIFIED), GLFW_FALSE);
+        glfwMaximizeWindow(window);
+        assert_eq!(glfwGetWindowAttrib(window, GLFW_MAXIMIZED), GLFW_TRUE);
+        glfwRestoreWindow(window);
+        assert_eq!(glfwGetWindowAttrib(window, GLFW_MAXIMIZED), GLFW_FALSE);
+        glfwDestroyWindow(window);
+    }
+    common::assert_no_error();
+}
+
+#[test]
+fn attribs() {
+    let _glfw = common::init();
+    let window = common::create_window(640, 480);
+    unsafe {
+        assert_eq!(glfwGetWindowAttrib(window, GLFW_CLIENT_API), GLFW_NO_API);
+        for attrib in [
+            GLFW_DECORATED,
+            GLFW_RESIZABLE,
+            GLFW_FLOATING,
+            GLFW_AUTO_ICONIFY,
+            GLFW_FOCUS_ON_SHOW,
+            GLFW_MOUSE_PASSTHROUGH,
+        ] {
+            glfwSetWindowAttrib(window, attrib, GLFW_TRUE);
+            assert_eq!(glfwGetWindowAttrib(window, attrib), GLFW_TRUE);
+            glfwSetWindowAttrib(window, attrib, GLFW_FALSE);
+            assert_eq!(glfwGetWindowAttrib(window, attrib), GLFW_FALSE);
+        }
+        glfwDestroyWindow(window);
+    }
+    common::assert_no_error();
+}
+
+#[test]
+fn hint_strings() {
+    let _glfw = common::init();
+    unsafe {
+        glfwWindowHintString(GLFW_X11_CLASS_NAME, c"class".as_ptr());
+        glfwWindowHintString(GLFW_X11_INSTANCE_NAME, c"instance".as_ptr());
+        glfwWindowHintString(GLFW_WAYLAND_APP_ID, c"app-id".as_ptr());
+        glfwWindowHintString(GLFW_COCOA_FRAME_NAME, c"frame".as_ptr());
+    }
+    let window = common::create_window(640, 480);
+    unsafe { glfwDestroyWindow(window) };
+    common::assert_no_error();
+}
+
+#[test]
+fn user_pointer() {
+    let _glfw = common::init();
+    let window = common::create_window(640, 480);
+    let mut data = 42;
+    unsafe {
+        assert!(glfwGetWindowUserPointer(window).is_null());
+        glfwSetWindowUserPointer(window, &mut data as *mut i32 as *mut _);
+        let pointer = glfwGetWindowUserPointer(window) as *mut i32;
+        assert_eq!(*pointer, 42);
+        glfwDestroyWindow(window);
+    }
+    common::assert_no_error();
+}
+
+#[test]
+fn fullscreen() {
+    let _glfw = common::init();
+    let window = common::create_window(640, 480);
+    unsafe {
+        let monitor = glfwGetPrimaryMonitor();
+        let mode = &*glfwGetVideoMode(monitor);
+        glfwSetWindowMonitor(
+            window,
+            monitor,
+            0,
+            0,
+            mode.width,
+            mode.height,
+            mode.refreshRate,
+        );
+        assert_eq!(glfwGetWindowMonitor(window), monitor);
+
+        glfwSetWindowMonitor(window, std::ptr::null_mut(), 10, 20, 640, 480, 0);
+        assert!(glfwGetWindowMonitor(window).is_null());
+        let (mut width, mut height) = (0, 0);
+        glfwGetWindowSize(window, &mut width, &mut height);
+        assert_eq!((width, height), (640, 480));
+        glfwDestroyWindow(window);
+    }
+    common::assert_no_error();
+}
+
+/// Events recorded by the window callbacks, stored in the window user pointer.
+#[derive(Default, Debug)]
+struct Events {
+    pos: Option<(i32, i32)>,
+    size: Option<(i32, i32)>,
+    framebuffer_size: Option<(i32, i32)>,
+    focused: Option<i32>,
+    iconified: Option<i32>,
+    maximized: Option<i32>,
+}
+unsafe fn events<'a>(window: *mut GLFWwindow) -> &'a mut Events {
+    &mut *(glfwGetWindowUserPointer(window) as *mut Events)
+}
+
+#[test]
+fn callbacks() {
+    unsafe extern "C" fn pos(window: *mut GLFWwindow, x: i32, y: i32) {
+        events(window).pos = Some((x, y));
+    }
+    unsafe extern "C" fn size(window: *mut GLFWwindow, width: i32, height: i32) {
+        events(window).size = Some((width, height));
+    }
+    unsafe extern "C" fn framebuffer_size(window: *mut GLFWwindow, width: i32, height: i32) {
+        events(window).framebuffer_size = Some((width, height));
+    }
+    unsafe extern "C" fn focus(window: *mut GLFWwindow, focused: i32) {
+        events(window).focused = Some(focused);
+    }
+    unsafe extern "C" fn iconify(window: *mut GLFWwindow, iconified: i32) {
+        events(window).iconified = Some(iconified);
+    }
+    unsafe extern "C" fn maximize(window: *mut GLFWwindow, maximized: i32) {
+        events(window).maximized = Some(maximized);
+    }
+    unsafe extern "C" fn close(_window: *mut GLFWwindow) {}
+    unsafe extern "C" fn refresh(_window: *mut GLFWwindow) {}
+    unsafe extern "C" fn content_scale(_window: *mut GLFWwindow, _xscale: f32, _yscale: f32) {}
+
+    let _glfw = common::init();
+    let window = common::create_window(640, 480);
+    let mut recorded = Events::default();
+    unsafe {
+        glfwSetWindowUserPointer(window, &mut recorded as *mut Events as *mut _);
+        assert!(glfwSetWindowPosCallback(window, Some(pos)).is_none());
+        assert!(glfwSetWindowSizeCallback(window, Some(size)).is_none());
+        assert!(glfwSetFramebufferSizeCallback(window, Some(framebuffer_size)).is_none());
+        assert!(glfwSetWindowFocusCallback(window, Some(focus)).is_none());
+        assert!(glfwSetWindowIconifyCallback(window, Some(iconify)).is_none());
+        assert!(glfwSetWindowMaximizeCallback(window, Some(maximize)).is_none());
+        assert!(glfwSetWindowCloseCallback(window, Some(close)).is_none());
+        assert!(glfwSetWindowRefreshCallback(window, Some(refresh)).is_none());
+        assert!(glfwSetWindowContentScaleCallback(window, Some(content_scale)).is_none());
+
+        // the null platform emits events immediately.
+        glfwSetWindowPos(window, 12, 34);
+        glfwSetWindowSize(window, 320, 240);
+        glfwHideWindow(window);
+        glfwShowWindow(window);
+        glfwFocusWindow(window);
+        glfwMaximizeWindow(window);
+        glfwIconifyWindow(window);
+        glfwPollEvents();
+        glfwWaitEventsTimeout(0.0);
+
+        let recorded = events(window);
+        assert_eq!(recorded.pos, Some((12, 34)));
+        assert_eq!(recorded.size, Some((320, 240)));
+        assert_eq!(recorded.framebuffer_size, Some((320, 240)));
+        assert_eq!(recorded.focused, Some(GLFW_TRUE));
+        assert_eq!(recorded.maximized, Some(GLFW_TRUE));
+        assert_eq!(recorded.iconified, Some(GLFW_TRUE));
+
+        // setting a callback returns the previous one.
+        assert!(glfwSetWindowPosCallback(window, None).is_some());
+        assert!(glfwSetWindowSizeCallback(window, None).is_some());
+        assert!(glfwSetFramebufferSizeCallback(window, None).is_some());
+        assert!(glfwSetWindowFocusCallback(window, None).is_some());
+        assert!(glfwSetWindowIconifyCallback(window, None).is_some());
+        assert!(glfwSetWindowMaximizeCallback(window, None).is_some());
+        assert!(glfwSetWindowCloseCallback(window, None).is_some());
+        assert!(glfwSetWindowRefreshCallback(window, None).is_some());
+        assert!(glfwSetWindowContentScaleCallback(window, None).is_some());
+        glfwDestroyWindow(window);
+    }
+    common::assert_no_error();
+}